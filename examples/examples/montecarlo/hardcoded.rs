@@ -12,7 +12,7 @@ fn main() {
 
     // describe the Monte Carlo process
     const N_MONTECARLO_SAMPLE: usize = 100;
-    let mut rng = rand::thread_rng();
+    let mut rng = rand::rng();
 
     // compute Ys from Xs
     let rets = args.map(closure);
@@ -17,9 +17,22 @@
 //!
 //! The following features can be enabled:
 //!
+//! - `adaptive` -- enable the Genz-Malik adaptive computation method, for hyperrectangles.
 //! - `boole` -- enable the Boole computation method.
+//! - `filon` -- enable the Filon computation method.
+//! - `gregory` -- enable the Gregory computation method.
+//! - `levin` -- enable the Levin computation method.
 //! - `montecarlo` -- enable the Monte-Carlo computation method.
+//! - `open` -- enable the midpoint & open Newton-Cotes computation methods.
+//! - `polar` -- enable the Lebedev, spherical product & polar Gauss computation methods, for
+//!   spheres, discs & annuli.
+//! - `richardson` -- enable the Richardson extrapolation computation method.
 //! - `romberg` -- enable the Romberg computation method.
+//! - `simplex` -- enable the Dunavant & Grundmann-Möller computation methods, for simplices &
+//!   polygons.
+//! - `singular` -- enable the Cauchy principal value & algebraic-logarithmic weight computation
+//!   methods.
+//! - `sparse` -- enable the Smolyak sparse grid computation method, for hyperrectangles.
 //!
 //! [NI]: https://en.wikipedia.org/wiki/Numerical_integration
 //! [GH]: https://github.com/imrn99/integraal
@@ -43,8 +56,15 @@ mod traits;
 
 // --- RE-EXPORTS
 
-pub use parameters::{ComputeMethod, DomainDescriptor, FunctionDescriptor};
-pub use structure::{Integraal, IntegraalError};
+pub use parameters::{
+    BaseRule, ComputeMethod, CurveDescriptor, DiscontinuityDetection, DomainDescriptor,
+    FunctionDescriptor, IteratedLevel, LogWeight, NestedRule, NodeGenerator, NonFinitePolicy,
+    Oscillator, Piece, RegionDescriptor, Spacing, Summation, SurfaceDescriptor,
+};
+pub use structure::{
+    Breakpoint, Comparison, Discontinuity, Evaluation, Integraal, IntegraalBuilder, IntegraalError,
+    Unset,
+};
 pub use traits::Scalar;
 
 // ------ TESTS
@@ -1,14 +1,18 @@
 //! integral parameterization code
 
-use crate::Scalar;
+use crate::{
+    structure::{Nodes, UniformGrid},
+    Scalar,
+};
+use std::sync::Arc;
 
 /// Domain description enum
 ///
 /// This represents a discretization of the integrated space.
 ///
-/// Only 1D domains are currently supported. the type used for values must implement [`Scalar`]; the trait
-/// is automatially implemented for types satisfying its requirements. In the future, adding support for
-/// higher dimension can be considered.
+/// This enum describes 1D domains; see [`RegionDescriptor`] for higher dimensions. the type used
+/// for values must implement [`Scalar`]; the trait is automatially implemented for types
+/// satisfying its requirements.
 #[derive(Debug, Clone)]
 pub enum DomainDescriptor<X: Scalar> {
     /// List of values taken by the variable on which we integrate.
@@ -22,6 +26,485 @@ pub enum DomainDescriptor<X: Scalar> {
         /// Total number of values
         n_step: usize,
     },
+    /// Description of a discretization whose values are computed on the fly.
+    ///
+    /// This is equivalent to the explicit domain built by the constructor of the same spacing,
+    /// without storing its values; this is useful for domains made of a huge number of samples.
+    Generated(NodeGenerator<X>),
+    /// Union of sub-domains, joined at breakpoints.
+    ///
+    /// Each piece is integrated separately, and results are summed. The last sample of a piece
+    /// should be equal to the first sample of the next one; this common value is a breakpoint.
+    /// Closures are evaluated using one-sided limits at breakpoints, i.e. each piece sees the
+    /// values of the function from its side of the breakpoint; when using values, the samples
+    /// of each piece are expected one after the other, so that breakpoints appear twice.
+    Piecewise(Vec<Piece<X>>),
+}
+
+impl<X: Scalar> DomainDescriptor<X> {
+    /// Build a uniform domain of `n` samples, from `start` to `end` (included).
+    ///
+    /// The step is computed from the limits, so the last sample may differ from `end` by a
+    /// rounding error.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn linspace(start: X, end: X, n: usize) -> Self {
+        DomainDescriptor::Uniform {
+            start,
+            step: (end - start) / X::from(n.saturating_sub(1).max(1)).unwrap(),
+            n_step: n,
+        }
+    }
+
+    /// Build an explicit domain of `n` samples, evenly spaced on a log scale from `10^start` to
+    /// `10^end` (included).
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn logspace(start: X, end: X, n: usize) -> Self {
+        let ten = X::from(10.0).unwrap();
+        Self::geomspace(ten.powf(start), ten.powf(end), n)
+    }
+
+    /// Build an explicit domain of `n` samples forming a geometric progression from `start` to
+    /// `end` (included).
+    ///
+    /// Both limits should be non-zero and of the same sign.
+    #[must_use]
+    pub fn geomspace(start: X, end: X, n: usize) -> Self {
+        NodeGenerator::new(Spacing::Geometric, start, end, n).explicit()
+    }
+
+    /// Build an explicit domain made of the `n` Chebyshev-Gauss nodes over `[start, end]`.
+    ///
+    /// Limits of the interval are not part of the nodes, so they are excluded from the integral.
+    #[must_use]
+    pub fn chebyshev_gauss(start: X, end: X, n: usize) -> Self {
+        NodeGenerator::new(Spacing::ChebyshevGauss, start, end, n).explicit()
+    }
+
+    /// Build an explicit domain made of the `n` Chebyshev-Lobatto nodes over `[start, end]`
+    /// (limits included).
+    #[must_use]
+    pub fn chebyshev_lobatto(start: X, end: X, n: usize) -> Self {
+        NodeGenerator::new(Spacing::ChebyshevLobatto, start, end, n).explicit()
+    }
+
+    /// Build an explicit domain made of the `n` Gauss-Legendre nodes over `[start, end]`.
+    ///
+    /// Limits of the interval are not part of the nodes, so they are excluded from the integral.
+    /// Note that the domain only describes where the function is sampled: the integral is
+    /// computed by the chosen method, not using Gauss-Legendre weights.
+    #[must_use]
+    pub fn gauss_legendre(start: X, end: X, n: usize) -> Self {
+        NodeGenerator::new(Spacing::GaussLegendre, start, end, n).explicit()
+    }
+
+    /// Return the number of samples making up the domain.
+    pub(crate) fn n_sample(&self) -> usize {
+        match self {
+            DomainDescriptor::Explicit(args) => args.len(),
+            DomainDescriptor::Uniform { n_step, .. } => *n_step,
+            DomainDescriptor::Generated(generator) => generator.n_sample,
+            DomainDescriptor::Piecewise(pieces) => {
+                pieces.iter().map(|piece| piece.domain.n_sample()).sum()
+            }
+        }
+    }
+
+    /// Return the sub-domain made of samples `lo` to `hi` (included).
+    ///
+    /// Uniform domains remain uniform; other domains are returned as explicit domains.
+    pub(crate) fn slice(&self, lo: usize, hi: usize) -> Self {
+        match self {
+            DomainDescriptor::Uniform { step, .. } => DomainDescriptor::Uniform {
+                start: self.abscissa(lo),
+                step: *step,
+                n_step: hi - lo + 1,
+            },
+            _ => DomainDescriptor::Explicit((lo..=hi).map(|idx| self.abscissa(idx)).collect()),
+        }
+    }
+
+    /// Return the value of the variable at the `idx`-th sample of the domain.
+    pub(crate) fn abscissa(&self, idx: usize) -> X {
+        match self {
+            DomainDescriptor::Explicit(args) => args[idx],
+            DomainDescriptor::Uniform {
+                start,
+                step,
+                n_step,
+            } => UniformGrid::new(*start, *step, *n_step).node(idx),
+            DomainDescriptor::Generated(generator) => generator.node(idx),
+            DomainDescriptor::Piecewise(pieces) => {
+                let mut idx = idx;
+                for piece in pieces {
+                    let n_sample = piece.domain.n_sample();
+                    if idx < n_sample {
+                        return piece.domain.abscissa(idx);
+                    }
+                    idx -= n_sample;
+                }
+                panic!("sample index out of bounds")
+            }
+        }
+    }
+}
+
+/// Piece of a [`DomainDescriptor::Piecewise`] domain
+#[derive(Debug, Clone)]
+pub struct Piece<X: Scalar> {
+    /// Discretization of the piece; nested piecewise domains are not supported
+    pub domain: DomainDescriptor<X>,
+    /// Method used for this piece, overriding the method of the integral if specified
+    pub method: Option<ComputeMethod>,
+}
+
+impl<X: Scalar> Piece<X> {
+    /// Build a piece integrated using the method of the integral.
+    #[must_use]
+    pub fn new(domain: DomainDescriptor<X>) -> Self {
+        Self {
+            domain,
+            method: None,
+        }
+    }
+
+    /// Set the method used for this piece.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn method(mut self, compute_method: ComputeMethod) -> Self {
+        self.method = Some(compute_method);
+        self
+    }
+}
+
+/// Node spacing enum
+///
+/// This describes the distribution of the nodes of a [`NodeGenerator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spacing {
+    /// Evenly spaced nodes.
+    Linear,
+    /// Nodes evenly spaced on a log scale, i.e. forming a geometric progression. Both limits
+    /// should be non-zero and of the same sign.
+    Geometric,
+    /// Chebyshev-Gauss nodes, i.e. roots of the Chebyshev polynomial of the first kind; limits are
+    /// excluded -- [reference](https://en.wikipedia.org/wiki/Chebyshev_nodes)
+    ChebyshevGauss,
+    /// Chebyshev-Lobatto nodes, i.e. extrema of the Chebyshev polynomial of the first kind;
+    /// limits are included -- [reference](https://en.wikipedia.org/wiki/Chebyshev_nodes)
+    ChebyshevLobatto,
+    /// Gauss-Legendre nodes, i.e. roots of the Legendre polynomial; limits are excluded --
+    /// [reference](https://en.wikipedia.org/wiki/Gauss%E2%80%93Legendre_quadrature)
+    GaussLegendre,
+}
+
+/// Lazily generated discretization
+///
+/// This describes a set of `n_sample` nodes distributed over `[start, end]`, computed on the fly
+/// when read. Nodes are ordered from `start` to `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeGenerator<X: Scalar> {
+    /// Distribution of the nodes
+    pub spacing: Spacing,
+    /// First limit of the interval
+    pub start: X,
+    /// Second limit of the interval
+    pub end: X,
+    /// Total number of nodes
+    pub n_sample: usize,
+}
+
+impl<X: Scalar> NodeGenerator<X> {
+    /// Constructor.
+    #[must_use]
+    pub fn new(spacing: Spacing, start: X, end: X, n_sample: usize) -> Self {
+        Self {
+            spacing,
+            start,
+            end,
+            n_sample,
+        }
+    }
+
+    /// Compute all nodes & return the corresponding explicit domain.
+    #[must_use]
+    pub fn explicit(&self) -> DomainDescriptor<X> {
+        DomainDescriptor::Explicit((0..self.n_sample).map(|idx| self.node(idx)).collect())
+    }
+}
+
+/// Region description enum
+///
+/// This represents a multi-dimensional integrated space. Contrary to domains, regions are
+/// generally not discretized by the user: evaluation points are chosen by the computation method,
+/// so regions are integrated using [`FunctionDescriptor::MultiClosure`]. Curves and surfaces are
+/// integrated using 1D methods over their parameters, and also accept
+/// [`FunctionDescriptor::VectorField`]; grids & scattered points are integrated using sampled
+/// values.
+#[derive(Debug, Clone)]
+pub enum RegionDescriptor<X: Scalar> {
+    /// Simplex of dimension `d`, described by its `d + 1` vertices of `d` coordinates, e.g. a
+    /// triangle in 2D or a tetrahedron in 3D.
+    Simplex(Vec<Vec<X>>),
+    /// Simple polygon, described by its vertices in order (either orientation), without repeating
+    /// the first vertex. The polygon is triangulated, and the integrals over each triangle are
+    /// summed.
+    Polygon(Vec<[X; 2]>),
+    /// Surface of a sphere in 3D; the function is evaluated at points `center + radius * u`,
+    /// where `u` is a unit vector, i.e. a direction if the sphere is the unit sphere centered on
+    /// the origin.
+    Sphere {
+        /// Center of the sphere
+        center: [X; 3],
+        /// Radius of the sphere
+        radius: X,
+    },
+    /// Disc in 2D.
+    Disc {
+        /// Center of the disc
+        center: [X; 2],
+        /// Radius of the disc
+        radius: X,
+    },
+    /// Annulus in 2D, i.e. the region between two concentric circles.
+    Annulus {
+        /// Center of the annulus
+        center: [X; 2],
+        /// Radius of the inner circle
+        inner_radius: X,
+        /// Radius of the outer circle
+        outer_radius: X,
+    },
+    /// Curve, over which line integrals are computed -- see [`CurveDescriptor`].
+    Curve(CurveDescriptor<X>),
+    /// Parametric surface, over which surface integrals are computed -- see
+    /// [`SurfaceDescriptor`].
+    Surface(SurfaceDescriptor<X>),
+    /// Regular grid of any dimension, integrated using the values of the function at its samples
+    /// (see [`FunctionDescriptor::Values`]). Values are expected in row-major order, i.e. the
+    /// last axis varies fastest, as in the buffers of most image & volume formats.
+    ///
+    /// The method is applied along each axis in turn; only the rectangle, trapezoid & Simpson's
+    /// rules are supported.
+    Grid {
+        /// Number of samples along each axis
+        shape: Vec<usize>,
+        /// Step between samples along each axis
+        spacing: Vec<X>,
+        /// Region of interest, as a flag per sample, in the same order as the values; values of
+        /// samples outside the region are replaced by zero, so the region is only resolved up to
+        /// the spacing of the grid
+        mask: Option<Vec<bool>>,
+    },
+    /// Axis-aligned hyperrectangle of any dimension, described by the limits of the integral
+    /// along each axis, e.g. `[(a, b), (c, d)]` for the rectangle `[a, b] x [c, d]`; lower limits
+    /// should be smaller than upper ones. Vector fields are integrated component-wise over
+    /// hyperrectangles, using [`Integraal::evaluate_vector`][crate::Integraal::evaluate_vector].
+    Hyperrectangle(Vec<(X, X)>),
+    /// Scattered points in 2D, integrated using the values of the function at the points (see
+    /// [`FunctionDescriptor::Values`]), in the same order.
+    ///
+    /// Points are triangulated (Delaunay), and the piecewise-linear interpolant of the values is
+    /// integrated exactly; this requires [`ComputeMethod::Trapezoid`], which it generalizes.
+    /// Points whose value is not finite are removed before triangulation, unless the
+    /// non-finite policy is [`NonFinitePolicy::Error`].
+    Scattered {
+        /// Sample points; they should be distinct, and not all collinear
+        points: Vec<[X; 2]>,
+        /// Simple polygon to which the integral is restricted, described as
+        /// [`RegionDescriptor::Polygon`]; the integral covers the convex hull of the points if
+        /// there is none, and parts of the polygon outside of the hull are ignored
+        clip: Option<Vec<[X; 2]>>,
+    },
+    /// Iterated integral, described by its levels from the outermost variable to the innermost
+    /// one -- see [`IteratedLevel`]. The function is evaluated at points `(x_0, ..., x_{d-1})`,
+    /// where `x_0` is the variable of the outermost level.
+    ///
+    /// Limits of each level depend on the outer variables, so that non-rectangular regions can be
+    /// described, e.g. the disc of radius `r` as `x` over `[-r, r]`, then `y` over
+    /// `[-sqrt(r^2 - x^2), sqrt(r^2 - x^2)]`.
+    Iterated(Vec<IteratedLevel<X>>),
+}
+
+/// Curve description enum
+///
+/// Curves are integrated using the 1D method of the integral over their parameter `t`. Scalar
+/// functions (see [`FunctionDescriptor::MultiClosure`]) are integrated with respect to arc length,
+/// i.e. the integrand is `f(gamma(t)) * |gamma'(t)|`; vector fields (see
+/// [`FunctionDescriptor::VectorField`]) are integrated along the curve, i.e. the integrand is
+/// `F(gamma(t)) . gamma'(t)`, which yields the work of the field, or its circulation over closed
+/// curves.
+#[derive(Clone)]
+pub enum CurveDescriptor<X: Scalar> {
+    /// Curve `gamma(t)` described by a closure returning the point of parameter `t`.
+    Parametric {
+        /// Point of the curve of given parameter
+        point: Arc<dyn Fn(X) -> Vec<X>>,
+        /// Derivative of the curve with respect to its parameter; if unspecified, it is
+        /// approximated using central finite differences
+        derivative: Option<Arc<dyn Fn(X) -> Vec<X>>>,
+        /// Domain of the parameter, sampled according to the method's requirements
+        parameter: DomainDescriptor<X>,
+    },
+    /// Polygonal chain, described by its vertices in order; each segment is parameterized over
+    /// `[0, 1]`, integrated separately, and results are summed.
+    Polyline {
+        /// Vertices of the chain; repeat the first vertex to describe a closed chain
+        vertices: Vec<Vec<X>>,
+        /// Number of uniform samples of the parameter over each segment
+        n_sample: usize,
+    },
+}
+
+impl<X: Scalar> std::fmt::Debug for CurveDescriptor<X> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CurveDescriptor::Parametric {
+                derivative,
+                parameter,
+                ..
+            } => f
+                .debug_struct("Parametric")
+                .field("point", &"<closure>")
+                .field("derivative", &derivative.as_ref().map(|_| "<closure>"))
+                .field("parameter", parameter)
+                .finish(),
+            CurveDescriptor::Polyline { vertices, n_sample } => f
+                .debug_struct("Polyline")
+                .field("vertices", vertices)
+                .field("n_sample", n_sample)
+                .finish(),
+        }
+    }
+}
+
+/// Parametric surface description
+///
+/// Surfaces `r(u, v)` are integrated using the tensor product of the 1D method of the integral
+/// over their parameters, i.e. the method is applied over `u` for each sample of `v`, then over
+/// `v`. Scalar functions (see [`FunctionDescriptor::MultiClosure`]) are integrated with respect
+/// to area, i.e. the integrand is `f(r(u, v)) * |r_u x r_v|`; vector fields (see
+/// [`FunctionDescriptor::VectorField`]) of surfaces in 3D are integrated through the surface, i.e.
+/// the integrand is `F(r(u, v)) . (r_u x r_v)`, which yields the flux of the field, oriented by
+/// the parameterization.
+///
+/// Scalar functions can also be described by their values over the parameter grid (see
+/// [`FunctionDescriptor::Values`]); values are expected row by row, i.e. the value at the `i`-th
+/// sample of `u` & the `j`-th sample of `v` is at index `j * n_u + i`.
+#[derive(Clone)]
+pub struct SurfaceDescriptor<X: Scalar> {
+    /// Point of the surface of given parameters
+    pub point: Arc<dyn Fn(X, X) -> Vec<X>>,
+    /// Partial derivatives of the surface with respect to `u` & `v`; if unspecified, they are
+    /// approximated using central finite differences
+    #[allow(clippy::type_complexity)]
+    pub partials: Option<Arc<dyn Fn(X, X) -> (Vec<X>, Vec<X>)>>,
+    /// Domain of the first parameter
+    pub u: DomainDescriptor<X>,
+    /// Domain of the second parameter
+    pub v: DomainDescriptor<X>,
+}
+
+impl<X: Scalar> SurfaceDescriptor<X> {
+    /// Constructor; partial derivatives are approximated using finite differences.
+    #[must_use]
+    pub fn new(
+        point: Arc<dyn Fn(X, X) -> Vec<X>>,
+        u: DomainDescriptor<X>,
+        v: DomainDescriptor<X>,
+    ) -> Self {
+        Self {
+            point,
+            partials: None,
+            u,
+            v,
+        }
+    }
+
+    /// Set the partial derivatives of the surface with respect to `u` & `v`.
+    #[allow(clippy::type_complexity)]
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn partials(mut self, partials: Arc<dyn Fn(X, X) -> (Vec<X>, Vec<X>)>) -> Self {
+        self.partials = Some(partials);
+        self
+    }
+}
+
+impl<X: Scalar> std::fmt::Debug for SurfaceDescriptor<X> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SurfaceDescriptor")
+            .field("point", &"<closure>")
+            .field("partials", &self.partials.as_ref().map(|_| "<closure>"))
+            .field("u", &self.u)
+            .field("v", &self.v)
+            .finish()
+    }
+}
+
+/// Iterated integral level description
+///
+/// Each level is integrated using a 1D method over a uniform discretization of its variable,
+/// between limits computed from the values of the outer variables, i.e. `x_0, ..., x_{k-1}` for
+/// the `k`-th level; the outermost level receives an empty slice. Levels whose limits are equal
+/// contribute zero; limits in decreasing order are only accepted if descending domains are
+/// allowed (see [`Integraal::allow_descending`][crate::Integraal::allow_descending]).
+#[derive(Clone)]
+pub struct IteratedLevel<X: Scalar> {
+    /// Lower limit of the variable, given the values of the outer variables
+    #[allow(clippy::type_complexity)]
+    pub lower: Arc<dyn Fn(&[X]) -> X>,
+    /// Upper limit of the variable, given the values of the outer variables
+    #[allow(clippy::type_complexity)]
+    pub upper: Arc<dyn Fn(&[X]) -> X>,
+    /// Number of uniform samples of the variable between its limits
+    pub n_sample: usize,
+    /// Method used for this level, overriding the method of the integral if specified
+    pub method: Option<ComputeMethod>,
+}
+
+impl<X: Scalar> IteratedLevel<X> {
+    /// Build a level integrated using the method of the integral.
+    #[allow(clippy::type_complexity)]
+    #[must_use]
+    pub fn new(
+        lower: Arc<dyn Fn(&[X]) -> X>,
+        upper: Arc<dyn Fn(&[X]) -> X>,
+        n_sample: usize,
+    ) -> Self {
+        Self {
+            lower,
+            upper,
+            n_sample,
+            method: None,
+        }
+    }
+
+    /// Build a level of constant limits, integrated using the method of the integral.
+    #[must_use]
+    pub fn constant(start: X, end: X, n_sample: usize) -> Self
+    where
+        X: 'static,
+    {
+        Self::new(Arc::new(move |_| start), Arc::new(move |_| end), n_sample)
+    }
+
+    /// Set the method used for this level.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn method(mut self, compute_method: ComputeMethod) -> Self {
+        self.method = Some(compute_method);
+        self
+    }
+}
+
+impl<X: Scalar> std::fmt::Debug for IteratedLevel<X> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IteratedLevel")
+            .field("lower", &"<closure>")
+            .field("upper", &"<closure>")
+            .field("n_sample", &self.n_sample)
+            .field("method", &self.method)
+            .finish()
+    }
 }
 
 /// Function description enum
@@ -38,6 +521,117 @@ where
     /// List of values taken by the function. An error will be raised at computation if the length
     /// of the list isn't consistent with the domain descriptor.
     Values(Vec<X>),
+    /// Oscillatory function, written as the real (`Cos`) or imaginary (`Sin`) part of
+    /// `amplitude(x) * e^{i * phase(x)}`, i.e. `amplitude(x) * cos(phase(x))` or
+    /// `amplitude(x) * sin(phase(x))`.
+    ///
+    /// This description can be used with all methods, which then evaluate the function as a
+    /// closure. Methods dedicated to oscillatory integrals (e.g. Levin's method) use the amplitude
+    /// and the phase separately.
+    Oscillatory {
+        /// Non-oscillatory amplitude of the function
+        amplitude: Box<dyn Fn(X) -> X>,
+        /// Phase of the function
+        phase: Box<dyn Fn(X) -> X>,
+        /// Derivative of the phase
+        phase_derivative: Box<dyn Fn(X) -> X>,
+        /// Part of the complex function that is integrated
+        kind: Oscillator,
+    },
+    /// Function of several variables, taking the coordinates of a point as input & returning the
+    /// image of that point; this is used to integrate over regions (see [`RegionDescriptor`]).
+    #[allow(clippy::type_complexity)]
+    MultiClosure(Box<dyn Fn(&[X]) -> X>),
+    /// Vector field, taking the coordinates of a point as input & returning the components of
+    /// the field at that point; this is used to compute work integrals along curves (see
+    /// [`CurveDescriptor`]) & flux integrals through surfaces (see [`SurfaceDescriptor`]).
+    #[allow(clippy::type_complexity)]
+    VectorField(Box<dyn Fn(&[X]) -> Vec<X>>),
+}
+
+impl<X: Scalar> FunctionDescriptor<X> {
+    /// Return the value of the function at `x`; this should not be used on values.
+    pub(crate) fn evaluate(&self, x: X) -> X {
+        match self {
+            FunctionDescriptor::Closure(closure) => closure(x),
+            FunctionDescriptor::Values(_)
+            | FunctionDescriptor::MultiClosure(_)
+            | FunctionDescriptor::VectorField(_) => unreachable!(),
+            FunctionDescriptor::Oscillatory {
+                amplitude,
+                phase,
+                kind,
+                ..
+            } => {
+                amplitude(x)
+                    * match kind {
+                        Oscillator::Sin => phase(x).sin(),
+                        Oscillator::Cos => phase(x).cos(),
+                    }
+            }
+        }
+    }
+}
+
+/// Oscillator enum
+///
+/// This describes the oscillating factor of an integrated function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Oscillator {
+    /// Sine function.
+    Sin,
+    /// Cosine function.
+    Cos,
+}
+
+/// Logarithmic weight enum
+///
+/// This describes the logarithmic factor of the weight used by
+/// [`ComputeMethod::AlgebraicLogarithmic`], over a domain of limits `a < b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogWeight {
+    /// No logarithmic factor.
+    None,
+    /// `log(x - a)` factor.
+    Start,
+    /// `log(b - x)` factor.
+    End,
+    /// `log(x - a) * log(b - x)` factor.
+    Both,
+}
+
+/// Base rule enum
+///
+/// This describes the Newton-Cotes rule whose step is repeatedly refined by
+/// [`ComputeMethod::Richardson`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseRule {
+    /// Midpoint rule.
+    Midpoint,
+    /// Trapezoid rule.
+    Trapezoid,
+    /// Simpson's rule.
+    Simpson,
+}
+
+/// Nested rule enum
+///
+/// This describes the 1D rule applied along each axis by [`ComputeMethod::Smolyak`]; rules are
+/// nested, i.e. the nodes of a level are also nodes of the next one, so that function values are
+/// shared between levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NestedRule {
+    /// Clenshaw-Curtis rules, using Chebyshev extrema; the rule of level `l > 0` has `2^l + 1`
+    /// nodes, and is exact for polynomials up to degree `2^l` --
+    /// [reference](https://en.wikipedia.org/wiki/Clenshaw%E2%80%93Curtis_quadrature)
+    ClenshawCurtis,
+    /// Gauss-Patterson rules, i.e. successive Kronrod extensions of the midpoint rule; the rule
+    /// of level `l` has `2^(l + 1) - 1` nodes, and is exact for polynomials up to degree
+    /// `3 * 2^l - 1` (`1` for level 0) --
+    /// [reference](https://doi.org/10.1090/S0025-5718-68-99866-9)
+    GaussPatterson,
+    /// Trapezoid rules, using equispaced nodes; the rule of level `l > 0` has `2^l + 1` nodes.
+    Trapezoid,
 }
 
 /// Numerical integration method enum
@@ -57,7 +651,7 @@ where
 /// The crate assumes that the first and last samples making up your domain corresponds to the
 /// limits of the integral. Therefore, these values will be ignored when computing the integral
 /// using rectangles.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ComputeMethod {
     /// Rectangle method, using the left rule --
     /// [reference](https://en.wikipedia.org/wiki/Riemann_sum#Left_rule)
@@ -67,6 +661,20 @@ pub enum ComputeMethod {
     RectangleRight,
     /// Trapezoid method -- [reference](https://en.wikipedia.org/wiki/Trapezoidal_rule)
     Trapezoid,
+    /// Midpoint rule, for closures; the function is never evaluated at the nodes of the domain --
+    /// [reference](https://en.wikipedia.org/wiki/Riemann_sum#Midpoint_rule)
+    #[cfg(feature = "open")]
+    Midpoint,
+    /// Open Newton-Cotes rule applied over each segment of the domain, for closures; the function
+    /// is never evaluated at the nodes of the domain, so that it may be undefined at its limits.
+    /// Rules of 1 & 2 points are exact for polynomials up to degree 1, rules of 3 & 4 points up to
+    /// degree 3, and the rule of 5 points up to degree 5 --
+    /// [reference](https://mathworld.wolfram.com/Newton-CotesFormulas.html)
+    #[cfg(feature = "open")]
+    OpenNewtonCotes {
+        /// Number of points per segment (between 1 and 5)
+        n_points: usize,
+    },
     /// Simpson's rule(s), the exact rule applied depends on integral definition --
     /// [reference](https://en.wikipedia.org/wiki/Simpson%27s_rule)
     Simpson,
@@ -79,13 +687,347 @@ pub enum ComputeMethod {
     /// Romberg's method -- [reference](https://en.wikipedia.org/wiki/Romberg%27s_method#Implementation)
     #[cfg(feature = "romberg")]
     Romberg {
-        /// Maximum number of iteration done by the algorithm
+        /// Maximum number of iteration done by the algorithm (between 1 and 31); with values,
+        /// this is also limited by the number of times the domain's segments can be halved
         max_steps: usize,
     },
+    /// Trapezoid rule with Gregory's endpoint corrections, also known as the Euler-Maclaurin
+    /// corrected trapezoid rule; only the weights of the first & last `order + 1` samples differ
+    /// from the ones of the trapezoid rule. It requires a uniform domain --
+    /// [reference](https://en.wikipedia.org/wiki/Gregory_coefficients)
+    #[cfg(feature = "gregory")]
+    Gregory {
+        /// Number of correction terms (between 1 and 8); the rule is exact for polynomials up to
+        /// this degree
+        order: usize,
+    },
+    /// Richardson extrapolation of a base rule, for closures over uniform domains; only the
+    /// limits of the domain are used. The step of the base rule is repeatedly halved, starting
+    /// from the whole domain, until the last two extrapolated estimates differ by at most the
+    /// tolerance; the step of the midpoint rule is divided by 3 instead, so that previous
+    /// evaluations are reused -- [reference](https://en.wikipedia.org/wiki/Richardson_extrapolation)
+    #[cfg(feature = "richardson")]
+    Richardson {
+        /// Rule whose step is halved
+        base: BaseRule,
+        /// Maximum number of estimates of the base rule (between 2 and 30)
+        levels: usize,
+        /// Absolute tolerance on the difference of the last two extrapolated estimates
+        tolerance: f64,
+    },
+    /// Dunavant's rules, for triangles & polygons; rules of degree `d` are exact for polynomials
+    /// of two variables up to degree `d` --
+    /// [reference](https://doi.org/10.1002/nme.1620210612)
+    #[cfg(feature = "simplex")]
+    Dunavant {
+        /// Degree of the rule (between 1 and 8)
+        degree: usize,
+    },
+    /// Grundmann-Möller rules, for simplices of any dimension & polygons; the rule of index `s`
+    /// is exact for polynomials up to degree `2s + 1`, and the one of the smallest sufficient
+    /// index is used --
+    /// [reference](https://doi.org/10.1137/0715019)
+    #[cfg(feature = "simplex")]
+    GrundmannMoller {
+        /// Minimum degree of the rule
+        degree: usize,
+    },
+    /// Lebedev's rules, for spheres; rules of 6, 14, 26, 38 and 50 points are exact for
+    /// polynomials up to degree 3, 5, 7, 9 and 11 respectively --
+    /// [reference](https://en.wikipedia.org/wiki/Lebedev_quadrature)
+    #[cfg(feature = "polar")]
+    Lebedev {
+        /// Number of points of the rule (6, 14, 26, 38 or 50)
+        n_points: usize,
+    },
+    /// Product rule for spheres, using a Gauss-Legendre rule over the cosine of the polar angle
+    /// & an equispaced rule over the azimuthal angle; it is exact for polynomials up to degree
+    /// `min(2 * n_polar - 1, n_azimuthal - 1)`
+    #[cfg(feature = "polar")]
+    SphericalProduct {
+        /// Number of Gauss-Legendre nodes over the polar angle
+        n_polar: usize,
+        /// Number of equispaced nodes over the azimuthal angle
+        n_azimuthal: usize,
+    },
+    /// Product rule for discs & annuli, using a Gauss-Legendre rule over the radius & an
+    /// equispaced rule over the angle; it is exact for polynomials up to degree
+    /// `min(2 * n_radial - 2, n_angular - 1)`
+    #[cfg(feature = "polar")]
+    PolarGauss {
+        /// Number of Gauss-Legendre nodes over the radius
+        n_radial: usize,
+        /// Number of equispaced nodes over the angle
+        n_angular: usize,
+    },
+    /// Smolyak's sparse grid rule, for hyperrectangles; it combines tensor products of nested
+    /// rules of low levels, so that the number of points grows slowly with the dimension. The
+    /// value comes with an error indicator (see [`Evaluation::error`][crate::Evaluation]), based on
+    /// the contributions of the last refinements --
+    /// [reference](https://en.wikipedia.org/wiki/Sparse_grid)
+    ///
+    /// Without tolerance, the rule of the given level is used, and the indicator is its
+    /// difference with the rule of the previous level. With a tolerance, the rule is refined
+    /// dimension-adaptively, favoring the axes along which the function varies most, until the
+    /// indicator falls below the tolerance; refinements are restricted to the points of the rule
+    /// of the given level --
+    /// [reference](https://doi.org/10.1007/s00607-003-0015-5)
+    #[cfg(feature = "sparse")]
+    Smolyak {
+        /// Nested rule used along each axis
+        rule: NestedRule,
+        /// Level of the rule, i.e. the maximum sum of the levels of the nested rules of the
+        /// tensor products (between 1 and 12, or 5 with Gauss-Patterson rules)
+        level: usize,
+        /// Absolute tolerance on the error indicator, enabling dimension-adaptive refinement
+        tolerance: Option<f64>,
+    },
+    /// Genz-Malik rule with global adaptive subdivision, for hyperrectangles; the subregion of
+    /// largest error is repeatedly halved, along the axis where the function varies most, until
+    /// the estimated error of the whole region is below the tolerance. The error is estimated
+    /// using an embedded rule of lower degree, and returned along with the number of evaluations
+    /// (see [`Evaluation`][crate::Evaluation]). Vector-valued functions are supported, and
+    /// integrated using [`Integraal::evaluate_vector`][crate::Integraal::evaluate_vector] --
+    /// [reference](https://doi.org/10.1016/0771-050X(80)90039-X)
+    #[cfg(feature = "adaptive")]
+    GenzMalik {
+        /// Absolute tolerance on the error
+        abs_tolerance: f64,
+        /// Tolerance on the error relative to the magnitude of the value
+        rel_tolerance: f64,
+        /// Maximum number of function evaluations; the computation fails if the tolerance is
+        /// not reached within this budget
+        max_evaluations: usize,
+    },
     #[cfg(feature = "montecarlo")]
     /// Monte-Carlo method -- [reference](https://en.wikipedia.org/wiki/Monte_Carlo_integration)
     MonteCarlo {
         /// Number of samples per step computation.
         n_sample: usize,
     },
+    /// Filon's method, integrating the product of the function with `sin(omega * x)` or
+    /// `cos(omega * x)`; its accuracy does not depend on `omega`. It requires a uniform domain
+    /// divided into an even number of segments --
+    /// [reference](https://mathworld.wolfram.com/FilonsIntegrationFormula.html)
+    #[cfg(feature = "filon")]
+    Filon {
+        /// Angular frequency of the oscillator
+        omega: f64,
+        /// Oscillator multiplying the function
+        kind: Oscillator,
+    },
+    /// Levin's collocation method, for functions described using
+    /// [`FunctionDescriptor::Oscillatory`]; only the limits of the domain are used, and its
+    /// accuracy does not depend on the frequency of the oscillations. The phase should not have
+    /// stationary points over the domain --
+    /// [reference](https://doi.org/10.1090/S0025-5718-1982-0645661-6)
+    #[cfg(feature = "levin")]
+    Levin {
+        /// Number of collocation nodes
+        n_nodes: usize,
+    },
+    /// Cauchy principal value of the integral of `f(x) / (x - pole)`, where `f` is the described
+    /// function; only the limits of the domain are used. The singularity is removed by pairing
+    /// points located symmetrically around the pole, and the integral is computed using
+    /// Gauss-Legendre rules over segments that grow geometrically away from the pole --
+    /// [reference](https://en.wikipedia.org/wiki/Cauchy_principal_value)
+    #[cfg(feature = "singular")]
+    CauchyPrincipalValue {
+        /// Location of the singularity, strictly inside the domain
+        pole: f64,
+        /// Number of Gauss-Legendre nodes per segment
+        n_nodes: usize,
+    },
+    /// Integral of `f(x) * (x - a)^alpha * (b - x)^beta * v(x)`, where `f` is the described
+    /// function, `a < b` are the limits of the domain, and `v` is a logarithmic factor; only the
+    /// limits of the domain are used. Algebraic singularities are removed by a change of
+    /// variable, and the integral is computed using the tanh-sinh rule --
+    /// [reference](https://en.wikipedia.org/wiki/Tanh-sinh_quadrature)
+    #[cfg(feature = "singular")]
+    AlgebraicLogarithmic {
+        /// Exponent of the algebraic factor at the lower limit, greater than -1
+        alpha: f64,
+        /// Exponent of the algebraic factor at the upper limit, greater than -1
+        beta: f64,
+        /// Logarithmic factor of the weight
+        log: LogWeight,
+        /// Number of halvings of the unit step of the tanh-sinh rule (at most 20); 3 to 5 is
+        /// usually enough to reach double precision
+        n_levels: usize,
+    },
+}
+
+impl ComputeMethod {
+    /// Return the name of the method, used in error messages.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            ComputeMethod::RectangleLeft => "rectangle method (left rule)",
+            ComputeMethod::RectangleRight => "rectangle method (right rule)",
+            ComputeMethod::Trapezoid => "trapezoid method",
+            ComputeMethod::Simpson => "Simpson's rule",
+            #[cfg(feature = "open")]
+            ComputeMethod::Midpoint => "midpoint rule",
+            #[cfg(feature = "open")]
+            ComputeMethod::OpenNewtonCotes { .. } => "open Newton-Cotes rule",
+            #[cfg(feature = "boole")]
+            ComputeMethod::Boole { .. } => "Boole's method",
+            #[cfg(feature = "romberg")]
+            ComputeMethod::Romberg { .. } => "Romberg's method",
+            #[cfg(feature = "gregory")]
+            ComputeMethod::Gregory { .. } => "Gregory's method",
+            #[cfg(feature = "richardson")]
+            ComputeMethod::Richardson { .. } => "Richardson extrapolation",
+            #[cfg(feature = "simplex")]
+            ComputeMethod::Dunavant { .. } => "Dunavant's rule",
+            #[cfg(feature = "simplex")]
+            ComputeMethod::GrundmannMoller { .. } => "Grundmann-Möller rule",
+            #[cfg(feature = "polar")]
+            ComputeMethod::Lebedev { .. } => "Lebedev's rule",
+            #[cfg(feature = "polar")]
+            ComputeMethod::SphericalProduct { .. } => "spherical product rule",
+            #[cfg(feature = "polar")]
+            ComputeMethod::PolarGauss { .. } => "polar Gauss rule",
+            #[cfg(feature = "sparse")]
+            ComputeMethod::Smolyak { .. } => "Smolyak's sparse grid rule",
+            #[cfg(feature = "adaptive")]
+            ComputeMethod::GenzMalik { .. } => "Genz-Malik adaptive rule",
+            #[cfg(feature = "montecarlo")]
+            ComputeMethod::MonteCarlo { .. } => "Monte-Carlo method",
+            #[cfg(feature = "filon")]
+            ComputeMethod::Filon { .. } => "Filon's method",
+            #[cfg(feature = "levin")]
+            ComputeMethod::Levin { .. } => "Levin's method",
+            #[cfg(feature = "singular")]
+            ComputeMethod::CauchyPrincipalValue { .. } => "Cauchy principal value method",
+            #[cfg(feature = "singular")]
+            ComputeMethod::AlgebraicLogarithmic { .. } => "algebraic-logarithmic weight method",
+        }
+    }
+
+    /// Return `true` if the method only samples closures at the domain's points.
+    ///
+    /// Results of such methods do not change if a closure is replaced by its values.
+    pub(crate) fn uses_domain_nodes(self) -> bool {
+        match self {
+            ComputeMethod::RectangleLeft
+            | ComputeMethod::RectangleRight
+            | ComputeMethod::Trapezoid
+            | ComputeMethod::Simpson => true,
+            // points are located between the domain's samples
+            #[cfg(feature = "open")]
+            ComputeMethod::Midpoint | ComputeMethod::OpenNewtonCotes { .. } => false,
+            #[cfg(feature = "boole")]
+            ComputeMethod::Boole { .. } => true,
+            // evaluation points depend on the number of steps, not on the domain's samples
+            #[cfg(feature = "romberg")]
+            ComputeMethod::Romberg { .. } => false,
+            #[cfg(feature = "gregory")]
+            ComputeMethod::Gregory { .. } => true,
+            #[cfg(feature = "richardson")]
+            ComputeMethod::Richardson { .. } => false,
+            // points are chosen over regions, not domains
+            #[cfg(feature = "simplex")]
+            ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. } => false,
+            #[cfg(feature = "polar")]
+            ComputeMethod::Lebedev { .. }
+            | ComputeMethod::SphericalProduct { .. }
+            | ComputeMethod::PolarGauss { .. } => false,
+            #[cfg(feature = "sparse")]
+            ComputeMethod::Smolyak { .. } => false,
+            #[cfg(feature = "adaptive")]
+            ComputeMethod::GenzMalik { .. } => false,
+            #[cfg(feature = "montecarlo")]
+            ComputeMethod::MonteCarlo { .. } => true,
+            #[cfg(feature = "filon")]
+            ComputeMethod::Filon { .. } => true,
+            // only the limits of the domain are used
+            #[cfg(feature = "levin")]
+            ComputeMethod::Levin { .. } => false,
+            #[cfg(feature = "singular")]
+            ComputeMethod::CauchyPrincipalValue { .. }
+            | ComputeMethod::AlgebraicLogarithmic { .. } => false,
+        }
+    }
+}
+
+/// Non-finite value handling policy
+///
+/// This controls how `NaN` or infinite function values are handled during computation. Values
+/// are checked as they are read (from a [`FunctionDescriptor::Values`] slice) or computed (by a
+/// [`FunctionDescriptor::Closure`]), so that corrupt samples do not silently poison the result.
+///
+/// Policies that require neighbouring samples (`Skip`, `Interpolate`) sample closures over the
+/// domain if a non-finite value is encountered, and then proceed as if values were provided; they
+/// are therefore rejected for methods that evaluate closures between the domain's samples, and
+/// for cubature rules over simplices, spheres, discs & annuli.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NonFinitePolicy {
+    /// Abort the computation and return an error describing the first faulty sample --
+    /// see [`IntegraalError::NonFinite`][crate::IntegraalError::NonFinite].
+    #[default]
+    Error,
+    /// Remove faulty samples from the integral definition; their neighbours are joined directly.
+    ///
+    /// Removing samples from a uniform domain makes it non-uniform: methods that require uniform
+    /// domains will return an error if a sample is skipped. Likewise, an error is returned if
+    /// too few samples are left for the method.
+    Skip,
+    /// Replace faulty values with zero.
+    ReplaceWithZero,
+    /// Replace faulty values using a linear interpolation of the nearest finite neighbours.
+    ///
+    /// Faulty samples at the boundaries of the domain take the value of their only finite
+    /// neighbour.
+    Interpolate,
+}
+
+/// Summation strategy enum
+///
+/// This controls how the contributions of each sample (or group of samples) are accumulated by
+/// the computation methods. Naive summation is the fastest, but its round-off error grows with
+/// the number of samples; this may dominate the discretization error of long integrations,
+/// especially using single precision floats.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Summation {
+    /// Sequential accumulation -- `O(n)` error growth.
+    #[default]
+    Naive,
+    /// Pairwise summation -- `O(log n)` error growth --
+    /// [reference](https://en.wikipedia.org/wiki/Pairwise_summation)
+    Pairwise,
+    /// Kahan-Babuška-Neumaier compensated summation -- `O(1)` error growth, to first order --
+    /// [reference](https://en.wikipedia.org/wiki/Kahan_summation_algorithm#Further_enhancements)
+    KahanBabuskaNeumaier,
+    /// Exact summation using error-free transformations; the result is correctly rounded --
+    /// [reference](https://doi.org/10.1007/PL00009321)
+    Exact,
+}
+
+/// Discontinuity detection settings
+///
+/// When enabled (see [`Integraal::detect_discontinuities`][crate::Integraal::detect_discontinuities]),
+/// function values are analyzed before computation to find jumps and kinks (discontinuities of
+/// the derivative). The integral is then split at detected discontinuities: each smooth piece is
+/// integrated using the requested method, while intervals containing a discontinuity are
+/// integrated using the trapezoid method. Pieces that are too short for the requested method
+/// also fall back to the trapezoid method.
+///
+/// Detection relies on divided differences; it only applies to [`FunctionDescriptor::Values`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscontinuityDetection {
+    /// Minimum ratio between the variation of the function over a segment and the variations
+    /// over neighbouring segments for the segment to be considered as containing a jump
+    pub jump_ratio: f64,
+    /// Minimum ratio between the change of slope at a sample and the changes of slope at nearby
+    /// samples for the sample to be considered as a kink
+    pub kink_ratio: f64,
+}
+
+impl Default for DiscontinuityDetection {
+    fn default() -> Self {
+        Self {
+            jump_ratio: 10.0,
+            kink_ratio: 10.0,
+        }
+    }
 }
@@ -0,0 +1,331 @@
+//! adaptive cubature code
+
+// ------ IMPORTS
+
+use super::summation::SumWith;
+use crate::{Evaluation, IntegraalError, NonFinitePolicy, Scalar, Summation};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+// ------ CONTENT
+
+/// Check that tolerances are valid, and that the budget allows at least one application of the
+/// rule in the given dimension.
+pub(crate) fn validate(
+    dim: usize,
+    (abs_tolerance, rel_tolerance, max_evaluations): (f64, f64, usize),
+) -> Result<(), IntegraalError> {
+    let valid = |tolerance: f64| tolerance.is_finite() && tolerance >= 0.0;
+    if !(valid(abs_tolerance) && valid(rel_tolerance))
+        || (abs_tolerance == 0.0 && rel_tolerance == 0.0)
+    {
+        return Err(IntegraalError::BadParameters(format!(
+            "tolerances should be finite & non-negative, and at least one of them positive (abs_tolerance: {abs_tolerance}, rel_tolerance: {rel_tolerance})"
+        )));
+    }
+    let n_points = genz_malik_points(dim);
+    if max_evaluations < n_points {
+        return Err(IntegraalError::BadParameters(format!(
+            "the evaluation budget should allow at least one application of the rule (max_evaluations: {max_evaluations}, points per rule: {n_points})"
+        )));
+    }
+    Ok(())
+}
+
+/// Return the number of points of the Genz-Malik rule in the given dimension, saturating on
+/// overflow.
+fn genz_malik_points(dim: usize) -> usize {
+    let corners = u32::try_from(dim)
+        .ok()
+        .and_then(|dim| 2_usize.checked_pow(dim))
+        .unwrap_or(usize::MAX);
+    (1 + 4 * dim + 2 * dim * dim.saturating_sub(1)).saturating_add(corners)
+}
+
+/// Subregion of the hyperrectangle, along with the estimates of its rule.
+struct Subregion<X: Scalar> {
+    center: Vec<X>,
+    half_widths: Vec<X>,
+    values: Vec<X>,
+    errors: Vec<X>,
+    /// Axis along which the subregion is split if refined
+    axis: usize,
+}
+
+impl<X: Scalar> Subregion<X> {
+    /// Return the largest error of the components.
+    fn priority(&self) -> X {
+        self.errors.iter().fold(X::zero(), |acc, e| acc.max(*e))
+    }
+}
+
+// subregions are ordered by error, so that the heap yields the worst one first
+
+impl<X: Scalar> PartialEq for Subregion<X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<X: Scalar> Eq for Subregion<X> {}
+
+impl<X: Scalar> PartialOrd for Subregion<X> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<X: Scalar> Ord for Subregion<X> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority()
+            .partial_cmp(&other.priority())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Vector-valued function, counting & checking its evaluations.
+struct Integrand<'a, X: Scalar> {
+    function: &'a dyn Fn(&[X]) -> Vec<X>,
+    policy: NonFinitePolicy,
+    n_components: Option<usize>,
+    evaluations: usize,
+}
+
+impl<X: Scalar> Integrand<'_, X> {
+    fn eval(&mut self, point: &[X]) -> Result<Vec<X>, IntegraalError> {
+        let mut values = (self.function)(point);
+        if *self.n_components.get_or_insert(values.len()) != values.len() {
+            return Err(IntegraalError::InconsistentParameters(String::from(
+                "vector-valued functions should always return the same number of components",
+            )));
+        }
+        if values.iter().any(|v| !v.is_finite()) {
+            if self.policy == NonFinitePolicy::Error {
+                return Err(IntegraalError::NonFinite {
+                    index: self.evaluations,
+                    abscissa: point[0].to_f64().unwrap(),
+                });
+            }
+            for v in values.iter_mut().filter(|v| !v.is_finite()) {
+                *v = X::zero();
+            }
+        }
+        self.evaluations += 1;
+        Ok(values)
+    }
+
+    /// Apply the Genz-Malik rule of degree 7 & its embedded rule of degree 5 over a subregion;
+    /// the error is the difference between the two.
+    ///
+    /// The subregion is to be split along the axis of the largest fourth divided difference,
+    /// i.e. along which the function is the least polynomial.
+    fn genz_malik(
+        &mut self,
+        center: Vec<X>,
+        half_widths: Vec<X>,
+    ) -> Result<Subregion<X>, IntegraalError> {
+        let dim = center.len();
+        let c = |x: f64| X::from(x).unwrap();
+        let (lambda2, lambda3, lambda5) = (
+            c((9.0_f64 / 70.0).sqrt()),
+            c(0.9_f64.sqrt()),
+            c((9.0_f64 / 19.0).sqrt()),
+        );
+
+        let mut point = center.clone();
+        let f_center = self.eval(&point)?;
+        let zero = vec![X::zero(); f_center.len()];
+        let add = |acc: &mut Vec<X>, values: &[X]| {
+            for (a, v) in acc.iter_mut().zip(values) {
+                *a = *a + *v;
+            }
+        };
+
+        // points along axes, keeping track of the fourth differences
+        let (mut s2, mut s3) = (zero.clone(), zero.clone());
+        let mut differences = Vec::with_capacity(dim);
+        for axis in 0..dim {
+            let mut pair = |lambda: X| -> Result<Vec<X>, IntegraalError> {
+                let mut sum = zero.clone();
+                for sign in [X::one(), -X::one()] {
+                    point[axis] = center[axis] + sign * lambda * half_widths[axis];
+                    add(&mut sum, &self.eval(&point)?);
+                }
+                point[axis] = center[axis];
+                Ok(sum)
+            };
+            let (p2, p3) = (pair(lambda2)?, pair(lambda3)?);
+            let two = X::one() + X::one();
+            let difference = p2
+                .iter()
+                .zip(&p3)
+                .zip(&f_center)
+                .fold(X::zero(), |acc, ((a, b), f)| {
+                    acc + (*a - two * *f - (*b - two * *f) / c(7.0)).abs()
+                });
+            differences.push(difference);
+            add(&mut s2, &p2);
+            add(&mut s3, &p3);
+        }
+
+        // points in planes spanned by pairs of axes
+        let mut s4 = zero.clone();
+        for i in 0..dim {
+            for j in i + 1..dim {
+                for (si, sj) in [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)] {
+                    point[i] = center[i] + c(si) * lambda3 * half_widths[i];
+                    point[j] = center[j] + c(sj) * lambda3 * half_widths[j];
+                    add(&mut s4, &self.eval(&point)?);
+                }
+                point[i] = center[i];
+                point[j] = center[j];
+            }
+        }
+
+        // corners of the scaled subregion
+        let mut s5 = zero.clone();
+        for corner in 0..1_usize << dim {
+            for (axis, x) in point.iter_mut().enumerate() {
+                let sign = if corner & (1 << axis) == 0 {
+                    X::one()
+                } else {
+                    -X::one()
+                };
+                *x = center[axis] + sign * lambda5 * half_widths[axis];
+            }
+            add(&mut s5, &self.eval(&point)?);
+        }
+
+        let volume = half_widths.iter().fold(X::one(), |acc, h| acc * (*h + *h));
+        let (weights7, weights5) = genz_malik_weights(dim);
+        let sums = [&f_center, &s2, &s3, &s4, &s5];
+        let rule = |weights: [X; 5], k: usize| {
+            volume
+                * weights
+                    .iter()
+                    .zip(sums)
+                    .fold(X::zero(), |acc, (w, s)| acc + *w * s[k])
+        };
+        let values: Vec<X> = (0..f_center.len()).map(|k| rule(weights7, k)).collect();
+        let errors = (0..f_center.len())
+            .map(|k| (values[k] - rule(weights5, k)).abs())
+            .collect();
+        // near-ties are broken in favor of the widest axis
+        let largest = differences.iter().fold(X::zero(), |acc, d| acc.max(*d));
+        let threshold = largest * (X::one() - c(1e-10));
+        let axis = (0..dim)
+            .filter(|axis| differences[*axis] >= threshold)
+            .max_by(|&a, &b| {
+                half_widths[a]
+                    .partial_cmp(&half_widths[b])
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(0);
+        Ok(Subregion {
+            center,
+            half_widths,
+            values,
+            errors,
+            axis,
+        })
+    }
+}
+
+/// Return the weights of the Genz-Malik rule of degree 7 & of its embedded rule of degree 5, for
+/// the center, the two sets of points along axes, the points in planes & the corners; the rules
+/// are relative to the volume of the region.
+fn genz_malik_weights<X: Scalar>(dim: usize) -> ([X; 5], [X; 5]) {
+    let n = dim as f64;
+    let weights7 = [
+        (12824.0 - 9120.0 * n + 400.0 * n * n) / 19683.0,
+        980.0 / 6561.0,
+        (1820.0 - 400.0 * n) / 19683.0,
+        200.0 / 19683.0,
+        6859.0 / 19683.0 / 2.0_f64.powi(i32::try_from(dim).unwrap()),
+    ];
+    let weights5 = [
+        (729.0 - 950.0 * n + 50.0 * n * n) / 729.0,
+        245.0 / 486.0,
+        (265.0 - 100.0 * n) / 1458.0,
+        25.0 / 729.0,
+        0.0,
+    ];
+    let c = |x: f64| X::from(x).unwrap();
+    (weights7.map(c), weights5.map(c))
+}
+
+/// Compute the integral of a vector-valued function over a hyperrectangle, component-wise,
+/// using the Genz-Malik rule with global adaptive subdivision.
+///
+/// The subregion of largest error is repeatedly halved, until the error of every component is
+/// below the absolute tolerance, or the relative tolerance times the magnitude of the
+/// component. Subdivisions are shared between components.
+pub(crate) fn genz_malik<X: Scalar>(
+    bounds: &[(X, X)],
+    function: &dyn Fn(&[X]) -> Vec<X>,
+    (abs_tolerance, rel_tolerance, max_evaluations): (f64, f64, usize),
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<Vec<Evaluation<X>>, IntegraalError> {
+    let half = X::from(0.5).unwrap();
+    let (abs_tolerance, rel_tolerance) = (
+        X::from(abs_tolerance).unwrap(),
+        X::from(rel_tolerance).unwrap(),
+    );
+    let n_points = genz_malik_points(bounds.len());
+    let mut integrand = Integrand {
+        function,
+        policy,
+        n_components: None,
+        evaluations: 0,
+    };
+
+    let first = integrand.genz_malik(
+        bounds.iter().map(|(a, b)| (*a + *b) * half).collect(),
+        bounds.iter().map(|(a, b)| (*b - *a) * half).collect(),
+    )?;
+    // running totals, used to check convergence; returned values are summed at the end
+    let (mut values, mut errors) = (first.values.clone(), first.errors.clone());
+    let mut regions = BinaryHeap::from([first]);
+    let converged = |values: &[X], errors: &[X]| {
+        values
+            .iter()
+            .zip(errors)
+            .all(|(v, e)| *e <= abs_tolerance.max(rel_tolerance * v.abs()))
+    };
+
+    while !converged(&values, &errors) {
+        if integrand.evaluations + 2 * n_points > max_evaluations {
+            let achieved_error = errors.iter().fold(X::zero(), |acc, e| acc.max(*e));
+            return Err(IntegraalError::NotConverged {
+                achieved_error: achieved_error.to_f64().unwrap(),
+                evaluations: integrand.evaluations,
+            });
+        }
+        let worst = regions.pop().unwrap();
+        let mut half_widths = worst.half_widths.clone();
+        half_widths[worst.axis] *= half;
+        for sign in [X::one(), -X::one()] {
+            let mut center = worst.center.clone();
+            center[worst.axis] = center[worst.axis] + sign * half_widths[worst.axis];
+            let region = integrand.genz_malik(center, half_widths.clone())?;
+            for k in 0..values.len() {
+                values[k] = values[k] + region.values[k];
+                errors[k] = errors[k] + region.errors[k];
+            }
+            regions.push(region);
+        }
+        for k in 0..values.len() {
+            values[k] = values[k] - worst.values[k];
+            errors[k] = errors[k] - worst.errors[k];
+        }
+    }
+
+    Ok((0..values.len())
+        .map(|k| Evaluation {
+            value: regions.iter().map(|r| r.values[k]).sum_with(summation),
+            breakpoints: Vec::new(),
+            error: Some(regions.iter().map(|r| r.errors[k]).sum_with(summation)),
+            evaluations: Some(integrand.evaluations),
+        })
+        .collect())
+}
@@ -0,0 +1,261 @@
+//! type-state builder code
+
+// ------ IMPORTS
+
+use super::{cubature, validation};
+use crate::{
+    ComputeMethod, DiscontinuityDetection, DomainDescriptor, FunctionDescriptor, Integraal,
+    IntegraalError, NonFinitePolicy, RegionDescriptor, Scalar, Summation,
+};
+use std::marker::PhantomData;
+
+// ------ CONTENT
+
+/// Marker type for components that are not set yet -- see [`IntegraalBuilder`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Unset;
+
+/// Type-state integral builder
+///
+/// This structure is an alternative to the pseudo-builder pattern of [`Integraal`]: the presence of
+/// each component is tracked using generic parameters, so that [`IntegraalBuilder::build`] is only
+/// available once the domain (or region), the function and the method are all set. Method-specific
+/// requirements (e.g. Boole's method requiring a uniform domain) are checked when building the
+/// integral, not when computing it.
+///
+/// # Example
+///
+/// ```rust
+/// # use integraal::{DomainDescriptor, ComputeMethod, FunctionDescriptor, Integraal};
+/// # fn main() {
+/// let integral = Integraal::builder()
+///     .domain(DomainDescriptor::Uniform {
+///         start: 0.0,
+///         step: 0.00001,
+///         n_step: 100_001,
+///     })
+///     .function(FunctionDescriptor::Closure(Box::new(|x: f64| 2.0 * x)))
+///     .method(ComputeMethod::Trapezoid)
+///     .build()
+///     .unwrap();
+///
+/// assert!(integral.evaluate().is_ok());
+/// # }
+/// ```
+///
+/// Incomplete integrals are rejected at compile time:
+///
+/// ```rust,compile_fail
+/// # use integraal::{DomainDescriptor, FunctionDescriptor, Integraal};
+/// # fn main() {
+/// let integral = Integraal::builder()
+///     .domain(DomainDescriptor::Explicit(vec![0.0, 0.5, 1.0]))
+///     .function(FunctionDescriptor::Values(vec![0.0, 1.0, 2.0]))
+///     .build(); // no method specified
+/// # }
+/// ```
+pub struct IntegraalBuilder<X: Scalar, D = Unset, F = Unset, M = Unset> {
+    domain: D,
+    function: F,
+    method: M,
+    non_finite: NonFinitePolicy,
+    allow_descending: bool,
+    periodic: bool,
+    summation: Summation,
+    detection: Option<DiscontinuityDetection>,
+    scalar: PhantomData<X>,
+}
+
+impl<X: Scalar> Integraal<X> {
+    /// Create a type-state builder -- see [`IntegraalBuilder`].
+    #[must_use]
+    pub fn builder() -> IntegraalBuilder<X> {
+        IntegraalBuilder {
+            domain: Unset,
+            function: Unset,
+            method: Unset,
+            non_finite: NonFinitePolicy::default(),
+            allow_descending: false,
+            periodic: false,
+            summation: Summation::default(),
+            detection: None,
+            scalar: PhantomData,
+        }
+    }
+}
+
+impl<X: Scalar, D, F, M> IntegraalBuilder<X, D, F, M> {
+    /// Set the domain descriptor.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn domain(
+        self,
+        domain_descriptor: DomainDescriptor<X>,
+    ) -> IntegraalBuilder<X, DomainDescriptor<X>, F, M> {
+        IntegraalBuilder {
+            domain: domain_descriptor,
+            function: self.function,
+            method: self.method,
+            non_finite: self.non_finite,
+            allow_descending: self.allow_descending,
+            periodic: self.periodic,
+            summation: self.summation,
+            detection: self.detection,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Set the region descriptor -- see [`Integraal::region`].
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn region(
+        self,
+        region_descriptor: RegionDescriptor<X>,
+    ) -> IntegraalBuilder<X, RegionDescriptor<X>, F, M> {
+        IntegraalBuilder {
+            domain: region_descriptor,
+            function: self.function,
+            method: self.method,
+            non_finite: self.non_finite,
+            allow_descending: self.allow_descending,
+            periodic: self.periodic,
+            summation: self.summation,
+            detection: self.detection,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Set the function descriptor.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn function(
+        self,
+        function_descriptor: FunctionDescriptor<X>,
+    ) -> IntegraalBuilder<X, D, FunctionDescriptor<X>, M> {
+        IntegraalBuilder {
+            domain: self.domain,
+            function: function_descriptor,
+            method: self.method,
+            non_finite: self.non_finite,
+            allow_descending: self.allow_descending,
+            periodic: self.periodic,
+            summation: self.summation,
+            detection: self.detection,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Set the numerical integration method.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn method(self, compute_method: ComputeMethod) -> IntegraalBuilder<X, D, F, ComputeMethod> {
+        IntegraalBuilder {
+            domain: self.domain,
+            function: self.function,
+            method: compute_method,
+            non_finite: self.non_finite,
+            allow_descending: self.allow_descending,
+            periodic: self.periodic,
+            summation: self.summation,
+            detection: self.detection,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Allow domains described in decreasing order -- see [`Integraal::allow_descending`].
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn allow_descending(mut self, allow: bool) -> Self {
+        self.allow_descending = allow;
+        self
+    }
+
+    /// Integrate the function as periodic over the domain -- see [`Integraal::periodic`].
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn periodic(mut self, periodic: bool) -> Self {
+        self.periodic = periodic;
+        self
+    }
+
+    /// Set the handling policy of non-finite function values -- see
+    /// [`Integraal::non_finite_policy`].
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Set the summation strategy -- see [`Integraal::summation`].
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn summation(mut self, strategy: Summation) -> Self {
+        self.summation = strategy;
+        self
+    }
+
+    /// Enable discontinuity detection -- see [`Integraal::detect_discontinuities`].
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn detect_discontinuities(mut self, settings: DiscontinuityDetection) -> Self {
+        self.detection = Some(settings);
+        self
+    }
+}
+
+impl<X: Scalar> IntegraalBuilder<X, DomainDescriptor<X>, FunctionDescriptor<X>, ComputeMethod> {
+    /// Check requirements of the method & build the integral, which is then ready to be
+    /// computed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the domain or the function do not fit the method's
+    /// requirements; function values are rejected if the method evaluates the function between
+    /// the domain's samples, and periodic functions if the method or the domain do not support
+    /// them -- see [`Integraal::periodic`].
+    pub fn build(self) -> Result<Integraal<X>, IntegraalError> {
+        validation::validate(&self.domain, self.method, self.allow_descending)?;
+        if self.periodic {
+            validation::validate_periodic(&self.domain, self.method)?;
+        }
+        if let FunctionDescriptor::Values(vals) = &self.function {
+            validation::validate_values(&self.domain, self.method)?;
+            if vals.len() != self.domain.n_sample() {
+                return Err(IntegraalError::LengthMismatch {
+                    domain: self.domain.n_sample(),
+                    function: vals.len(),
+                });
+            }
+        }
+
+        Ok(Integraal {
+            domain: Some(self.domain),
+            region: None,
+            function: Some(self.function),
+            method: Some(self.method),
+            non_finite: self.non_finite,
+            allow_descending: self.allow_descending,
+            periodic: self.periodic,
+            summation: self.summation,
+            detection: self.detection,
+        })
+    }
+}
+
+impl<X: Scalar> IntegraalBuilder<X, RegionDescriptor<X>, FunctionDescriptor<X>, ComputeMethod> {
+    /// Check requirements of the method & build the integral, which is then ready to be
+    /// computed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the region or the function do not fit the method's
+    /// requirements.
+    pub fn build(self) -> Result<Integraal<X>, IntegraalError> {
+        cubature::check_function(&self.domain, &self.function)?;
+        cubature::validate(&self.domain, self.method, self.allow_descending)?;
+
+        Ok(Integraal {
+            domain: None,
+            region: Some(self.domain),
+            function: Some(self.function),
+            method: Some(self.method),
+            non_finite: self.non_finite,
+            allow_descending: self.allow_descending,
+            periodic: self.periodic,
+            summation: self.summation,
+            detection: self.detection,
+        })
+    }
+}
@@ -0,0 +1,592 @@
+//! multi-dimensional integration code
+
+// ------ IMPORTS
+
+#[cfg(feature = "adaptive")]
+use super::adaptive;
+#[cfg(feature = "polar")]
+use super::polar;
+#[cfg(feature = "simplex")]
+use super::simplex;
+#[cfg(feature = "sparse")]
+use super::sparse;
+#[cfg(any(feature = "polar", feature = "simplex"))]
+use super::summation::SumWith;
+use super::{curve, iterated, scattered, surface, validation};
+use crate::{
+    ComputeMethod, DomainDescriptor, FunctionDescriptor, IntegraalError, RegionDescriptor, Scalar,
+};
+#[cfg(any(feature = "polar", feature = "simplex"))]
+use crate::{NonFinitePolicy, Summation};
+
+// ------ CONTENT
+
+/// Check that the function can be integrated over the region.
+pub(crate) fn check_function<X: Scalar>(
+    region: &RegionDescriptor<X>,
+    function: &FunctionDescriptor<X>,
+) -> Result<(), IntegraalError> {
+    if let RegionDescriptor::Surface(surface) = region {
+        return surface::check_function(surface, function);
+    }
+    if let RegionDescriptor::Grid { shape, .. } = region {
+        let FunctionDescriptor::Values(vals) = function else {
+            return Err(IntegraalError::InconsistentParameters(String::from(
+                "grids require function values (`FunctionDescriptor::Values`)",
+            )));
+        };
+        let n_sample = shape.iter().product();
+        return check_length(n_sample, vals.len());
+    }
+    if let RegionDescriptor::Scattered { points, .. } = region {
+        let FunctionDescriptor::Values(vals) = function else {
+            return Err(IntegraalError::InconsistentParameters(String::from(
+                "scattered points require function values (`FunctionDescriptor::Values`)",
+            )));
+        };
+        return check_length(points.len(), vals.len());
+    }
+    match (region, function) {
+        (_, FunctionDescriptor::MultiClosure(_))
+        | (RegionDescriptor::Curve(_), FunctionDescriptor::VectorField(_)) => Ok(()),
+        (RegionDescriptor::Hyperrectangle(_), FunctionDescriptor::VectorField(_)) => Err(IntegraalError::InconsistentParameters(String::from(
+            "vector fields are integrated component-wise over hyperrectangles, using `Integraal::evaluate_vector`",
+        ))),
+        (RegionDescriptor::Curve(_), _) => Err(IntegraalError::InconsistentParameters(String::from(
+            "curves require a function of several variables (`FunctionDescriptor::MultiClosure`) or a vector field (`FunctionDescriptor::VectorField`)",
+        ))),
+        _ => Err(IntegraalError::InconsistentParameters(String::from(
+            "regions require a function of several variables (`FunctionDescriptor::MultiClosure`)",
+        ))),
+    }
+}
+
+/// Check that the region fits the requirements of the method.
+///
+/// Curves & surfaces are checked against 1D methods, using their parameters, as are the levels of
+/// iterated integrals; `allow_descending` applies to the latter two.
+pub(crate) fn validate<X: Scalar>(
+    region: &RegionDescriptor<X>,
+    method: ComputeMethod,
+    allow_descending: bool,
+) -> Result<(), IntegraalError> {
+    if let RegionDescriptor::Curve(curve) = region {
+        return curve::validate(curve, method, allow_descending);
+    }
+    if let RegionDescriptor::Surface(surface) = region {
+        return surface::validate(surface, method, allow_descending);
+    }
+    if let RegionDescriptor::Iterated(levels) = region {
+        return iterated::validate(levels, method, allow_descending);
+    }
+    if let RegionDescriptor::Grid {
+        shape,
+        spacing,
+        mask,
+    } = region
+    {
+        return validate_grid(shape, spacing, mask.as_deref(), method);
+    }
+    if let RegionDescriptor::Scattered { points, clip } = region {
+        return validate_scattered(points, clip.as_deref(), method);
+    }
+    validate_geometry(region)?;
+    validate_rule(region, method)
+}
+
+/// Check that the method is a cubature rule fitting the region, whose geometry is valid.
+#[allow(clippy::too_many_lines)]
+fn validate_rule<X: Scalar>(
+    region: &RegionDescriptor<X>,
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    match (method, region) {
+        #[cfg(feature = "simplex")]
+        (
+            ComputeMethod::Dunavant { degree },
+            RegionDescriptor::Simplex(_) | RegionDescriptor::Polygon(_),
+        ) => {
+            if degree == 0 || degree > 8 {
+                return Err(IntegraalError::BadParameters(format!(
+                    "Dunavant's rules are available from degree 1 to 8 (degree: {degree})"
+                )));
+            }
+            if matches!(region, RegionDescriptor::Simplex(vertices) if vertices.len() != 3) {
+                return Err(IntegraalError::Unimplemented {
+                    method,
+                    context: "simplices that are not triangles",
+                });
+            }
+            simplices(region).map(|_| ())
+        }
+        #[cfg(feature = "simplex")]
+        (
+            ComputeMethod::GrundmannMoller { .. },
+            RegionDescriptor::Simplex(_) | RegionDescriptor::Polygon(_),
+        ) => simplices(region).map(|_| ()),
+        #[cfg(feature = "polar")]
+        (ComputeMethod::Lebedev { n_points }, RegionDescriptor::Sphere { .. }) => {
+            if polar::LEBEDEV_SIZES.contains(&n_points) {
+                Ok(())
+            } else {
+                Err(IntegraalError::BadParameters(format!(
+                    "Lebedev's rules are available with {:?} points (n_points: {n_points})",
+                    polar::LEBEDEV_SIZES
+                )))
+            }
+        }
+        #[cfg(feature = "polar")]
+        (
+            ComputeMethod::SphericalProduct {
+                n_polar,
+                n_azimuthal,
+            },
+            RegionDescriptor::Sphere { .. },
+        ) => check_nodes(
+            method,
+            &[("n_polar", n_polar), ("n_azimuthal", n_azimuthal)],
+        ),
+        #[cfg(feature = "polar")]
+        (
+            ComputeMethod::PolarGauss {
+                n_radial,
+                n_angular,
+            },
+            RegionDescriptor::Disc { .. } | RegionDescriptor::Annulus { .. },
+        ) => check_nodes(method, &[("n_radial", n_radial), ("n_angular", n_angular)]),
+        #[cfg(feature = "sparse")]
+        (ComputeMethod::Smolyak { rule, level, .. }, RegionDescriptor::Hyperrectangle(_)) => {
+            sparse::validate(rule, level)
+        }
+        #[cfg(feature = "adaptive")]
+        (
+            ComputeMethod::GenzMalik {
+                abs_tolerance,
+                rel_tolerance,
+                max_evaluations,
+            },
+            RegionDescriptor::Hyperrectangle(bounds),
+        ) => adaptive::validate(
+            bounds.len(),
+            (abs_tolerance, rel_tolerance, max_evaluations),
+        ),
+        // cubature methods over other kinds of regions
+        #[cfg(feature = "simplex")]
+        (ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. }, _) => {
+            Err(IntegraalError::Unimplemented {
+                method,
+                context: kind(region),
+            })
+        }
+        #[cfg(feature = "polar")]
+        (
+            ComputeMethod::Lebedev { .. }
+            | ComputeMethod::SphericalProduct { .. }
+            | ComputeMethod::PolarGauss { .. },
+            _,
+        ) => Err(IntegraalError::Unimplemented {
+            method,
+            context: kind(region),
+        }),
+        #[cfg(feature = "sparse")]
+        (ComputeMethod::Smolyak { .. }, _) => Err(IntegraalError::Unimplemented {
+            method,
+            context: kind(region),
+        }),
+        #[cfg(feature = "adaptive")]
+        (ComputeMethod::GenzMalik { .. }, _) => Err(IntegraalError::Unimplemented {
+            method,
+            context: kind(region),
+        }),
+        _ => Err(IntegraalError::Unimplemented {
+            method,
+            context: "regions",
+        }),
+    }
+}
+
+/// Compute the integral of a function of several variables over a region.
+///
+/// The region is expected to be valid for the method.
+#[cfg(any(feature = "polar", feature = "simplex"))]
+pub(crate) fn integrate<X: Scalar>(
+    region: &RegionDescriptor<X>,
+    function: &dyn Fn(&[X]) -> X,
+    method: ComputeMethod,
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    match method {
+        #[cfg(feature = "simplex")]
+        ComputeMethod::Dunavant { degree } => {
+            let rule = simplex::dunavant(degree);
+            integrate_simplices(region, &rule, function, policy, summation)
+        }
+        #[cfg(feature = "simplex")]
+        ComputeMethod::GrundmannMoller { degree } => {
+            let dim = match region {
+                RegionDescriptor::Simplex(vertices) => vertices.len() - 1,
+                _ => 2,
+            };
+            let rule = simplex::grundmann_moller(dim, degree);
+            integrate_simplices(region, &rule, function, policy, summation)
+        }
+        #[cfg(feature = "polar")]
+        ComputeMethod::Lebedev { n_points } => {
+            let rule = polar::lebedev(n_points);
+            integrate_sphere(region, rule, function, policy, summation)
+        }
+        #[cfg(feature = "polar")]
+        ComputeMethod::SphericalProduct {
+            n_polar,
+            n_azimuthal,
+        } => {
+            let rule = polar::spherical_product(n_polar, n_azimuthal);
+            integrate_sphere(region, rule, function, policy, summation)
+        }
+        #[cfg(feature = "polar")]
+        ComputeMethod::PolarGauss {
+            n_radial,
+            n_angular,
+        } => {
+            let (center, radii) = match region {
+                RegionDescriptor::Disc { center, radius } => (center, (X::zero(), *radius)),
+                RegionDescriptor::Annulus {
+                    center,
+                    inner_radius,
+                    outer_radius,
+                } => (center, (*inner_radius, *outer_radius)),
+                _ => unreachable!(),
+            };
+            let points = polar::polar_gauss(radii, n_radial, n_angular)
+                .into_iter()
+                .map(|(offset, weight)| {
+                    (vec![center[0] + offset[0], center[1] + offset[1]], weight)
+                });
+            weighted_sum(points, function, policy, summation)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Sum the weighted values of the function at the given points.
+///
+/// Non-finite values are handled according to the policy; as there is no notion of neighbouring
+/// samples between the points of a rule, the skip & interpolation policies are rejected.
+#[cfg(any(feature = "polar", feature = "simplex"))]
+fn weighted_sum<X: Scalar>(
+    points: impl Iterator<Item = (Vec<X>, X)>,
+    function: &dyn Fn(&[X]) -> X,
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    if matches!(policy, NonFinitePolicy::Skip | NonFinitePolicy::Interpolate) {
+        return Err(IntegraalError::InconsistentParameters(format!(
+            "the {policy:?} non-finite policy requires neighbouring samples, which the points of cubature rules do not have"
+        )));
+    }
+    let terms: Vec<X> = points
+        .enumerate()
+        .map(|(idx, (point, weight))| {
+            let value = function(&point);
+            match (value.is_finite(), policy) {
+                (true, _) => Ok(weight * value),
+                (false, NonFinitePolicy::Error) => Err(IntegraalError::NonFinitePoint {
+                    index: idx,
+                    point: point.iter().map(|x| x.to_f64().unwrap()).collect(),
+                }),
+                (false, _) => Ok(X::zero()),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(terms.into_iter().sum_with(summation))
+}
+
+/// Check that all coordinates are finite.
+fn check_finite<'a, X: Scalar + 'a>(
+    coordinates: impl Iterator<Item = &'a X>,
+) -> Result<(), IntegraalError> {
+    if coordinates.into_iter().all(|x| x.is_finite()) {
+        Ok(())
+    } else {
+        Err(IntegraalError::BadParameters(String::from(
+            "region vertices should have finite coordinates",
+        )))
+    }
+}
+
+/// Check that the geometry of the region is valid, independently of the method.
+fn validate_geometry<X: Scalar>(region: &RegionDescriptor<X>) -> Result<(), IntegraalError> {
+    match region {
+        RegionDescriptor::Simplex(vertices) => {
+            let dim = vertices.first().map_or(0, Vec::len);
+            if dim == 0 || vertices.len() != dim + 1 {
+                return Err(IntegraalError::BadParameters(format!(
+                    "a simplex of dimension d should be described by d + 1 vertices (dimension: {dim}, vertices: {})",
+                    vertices.len()
+                )));
+            }
+            if let Some(idx) = vertices.iter().position(|v| v.len() != dim) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "simplex vertices should have the same number of coordinates (vertex {idx}: {}, expected: {dim})",
+                    vertices[idx].len()
+                )));
+            }
+            check_finite(vertices.iter().flatten())?;
+        }
+        RegionDescriptor::Polygon(vertices) => {
+            if vertices.len() < 3 {
+                return Err(IntegraalError::BadParameters(format!(
+                    "a polygon should have at least 3 vertices (vertices: {})",
+                    vertices.len()
+                )));
+            }
+            check_finite(vertices.iter().flatten())?;
+            if let Some(idx) =
+                (0..vertices.len()).position(|i| vertices[i] == vertices[(i + 1) % vertices.len()])
+            {
+                return Err(IntegraalError::BadParameters(format!(
+                    "polygon contains duplicate consecutive vertices at indices {idx} and {}",
+                    (idx + 1) % vertices.len()
+                )));
+            }
+        }
+        RegionDescriptor::Sphere { center, radius } => {
+            check_finite(center.iter().chain([radius]))?;
+            check_radii(X::zero(), *radius)?;
+        }
+        RegionDescriptor::Disc { center, radius } => {
+            check_finite(center.iter().chain([radius]))?;
+            check_radii(X::zero(), *radius)?;
+        }
+        RegionDescriptor::Annulus {
+            center,
+            inner_radius,
+            outer_radius,
+        } => {
+            check_finite(center.iter().chain([inner_radius, outer_radius]))?;
+            check_radii(*inner_radius, *outer_radius)?;
+        }
+        RegionDescriptor::Hyperrectangle(bounds) => {
+            if bounds.is_empty() {
+                return Err(IntegraalError::BadParameters(String::from(
+                    "a hyperrectangle should have at least one axis",
+                )));
+            }
+            check_finite(bounds.iter().flat_map(|(start, end)| [start, end]))?;
+            if let Some(axis) = bounds.iter().position(|(start, end)| start >= end) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "hyperrectangle limits should be in increasing order (axis {axis}: {:?})",
+                    bounds[axis]
+                )));
+            }
+        }
+        // checked along with the method
+        RegionDescriptor::Curve(_)
+        | RegionDescriptor::Surface(_)
+        | RegionDescriptor::Grid { .. }
+        | RegionDescriptor::Scattered { .. }
+        | RegionDescriptor::Iterated(_) => {}
+    }
+    Ok(())
+}
+
+/// Check that the grid is consistent, and fits the requirements of the method along each axis.
+fn validate_grid<X: Scalar>(
+    shape: &[usize],
+    spacing: &[X],
+    mask: Option<&[bool]>,
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    if shape.is_empty() || shape.len() != spacing.len() {
+        return Err(IntegraalError::BadParameters(format!(
+            "grid shape & spacing should describe the same, non-zero number of axes (shape: {}, spacing: {})",
+            shape.len(),
+            spacing.len()
+        )));
+    }
+    if let Some(axis) = spacing
+        .iter()
+        .position(|h| !h.is_finite() || *h <= X::zero())
+    {
+        return Err(IntegraalError::BadParameters(format!(
+            "grid spacing should be finite & strictly positive (axis {axis}: {:?})",
+            spacing[axis]
+        )));
+    }
+    let n_sample: usize = shape.iter().product();
+    if let Some(mask) = mask.filter(|mask| mask.len() != n_sample) {
+        return Err(IntegraalError::BadParameters(format!(
+            "grid mask should have one flag per sample (mask: {}, samples: {n_sample})",
+            mask.len()
+        )));
+    }
+    if !matches!(
+        method,
+        ComputeMethod::RectangleLeft
+            | ComputeMethod::RectangleRight
+            | ComputeMethod::Trapezoid
+            | ComputeMethod::Simpson
+    ) {
+        return Err(IntegraalError::Unimplemented {
+            method,
+            context: "grids",
+        });
+    }
+    for (n_step, step) in shape.iter().zip(spacing) {
+        validation::validate(
+            &DomainDescriptor::Uniform {
+                start: X::zero(),
+                step: *step,
+                n_step: *n_step,
+            },
+            method,
+            false,
+        )?;
+    }
+    Ok(())
+}
+
+/// Check that scattered points can be triangulated, and that the method is the trapezoid rule,
+/// which their piecewise-linear interpolation generalizes.
+fn validate_scattered<X: Scalar>(
+    points: &[[X; 2]],
+    clip: Option<&[[X; 2]]>,
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    if method != ComputeMethod::Trapezoid {
+        return Err(IntegraalError::Unimplemented {
+            method,
+            context: "scattered points",
+        });
+    }
+    scattered::validate(points, clip)
+}
+
+/// Check that there is one value per sample.
+fn check_length(n_sample: usize, n_value: usize) -> Result<(), IntegraalError> {
+    if n_value == n_sample {
+        Ok(())
+    } else {
+        Err(IntegraalError::LengthMismatch {
+            domain: n_sample,
+            function: n_value,
+        })
+    }
+}
+
+/// Check that radii describe a non-empty region.
+fn check_radii<X: Scalar>(inner: X, outer: X) -> Result<(), IntegraalError> {
+    if inner >= X::zero() && inner < outer {
+        Ok(())
+    } else {
+        Err(IntegraalError::BadParameters(format!(
+            "radii should satisfy 0 <= inner < outer, i.e. describe a non-empty region (inner: {inner:?}, outer: {outer:?})"
+        )))
+    }
+}
+
+/// Check that each direction of a product rule has at least one node.
+#[cfg(feature = "polar")]
+fn check_nodes(method: ComputeMethod, counts: &[(&str, usize)]) -> Result<(), IntegraalError> {
+    match counts.iter().find(|(_, count)| *count == 0) {
+        Some((name, _)) => Err(IntegraalError::BadParameters(format!(
+            "the {} requires at least 1 node per direction ({name}: 0)",
+            method.name()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Return a description of the kind of region, used in errors.
+#[cfg(any(
+    feature = "adaptive",
+    feature = "polar",
+    feature = "simplex",
+    feature = "sparse"
+))]
+fn kind<X: Scalar>(region: &RegionDescriptor<X>) -> &'static str {
+    match region {
+        RegionDescriptor::Simplex(_) => "simplices",
+        RegionDescriptor::Polygon(_) => "polygons",
+        RegionDescriptor::Sphere { .. } => "spheres",
+        RegionDescriptor::Disc { .. } => "discs",
+        RegionDescriptor::Annulus { .. } => "annuli",
+        RegionDescriptor::Curve(_) => "curves",
+        RegionDescriptor::Surface(_) => "surfaces",
+        RegionDescriptor::Hyperrectangle(_) => "hyperrectangles",
+        RegionDescriptor::Grid { .. } => "grids",
+        RegionDescriptor::Scattered { .. } => "scattered points",
+        RegionDescriptor::Iterated(_) => "iterated integrals",
+    }
+}
+
+/// Split the region into non-degenerate simplices.
+#[cfg(feature = "simplex")]
+fn simplices<X: Scalar>(region: &RegionDescriptor<X>) -> Result<Vec<Vec<Vec<X>>>, IntegraalError> {
+    let simplices = match region {
+        RegionDescriptor::Simplex(vertices) => vec![vertices.clone()],
+        RegionDescriptor::Polygon(vertices) => simplex::triangulate(vertices)
+            .ok_or_else(|| {
+                IntegraalError::BadParameters(String::from(
+                    "polygon could not be triangulated; it should be simple, i.e. its edges should not intersect",
+                ))
+            })?
+            .into_iter()
+            .map(|triangle| triangle.iter().map(|i| vertices[*i].to_vec()).collect())
+            .collect(),
+        _ => unreachable!(),
+    };
+    if simplices
+        .iter()
+        .any(|vertices| simplex::volume(vertices).is_zero())
+    {
+        return Err(IntegraalError::BadParameters(String::from(
+            "region should not be degenerate, i.e. its volume should be strictly positive",
+        )));
+    }
+    Ok(simplices)
+}
+
+/// Apply a rule over each simplex of the region, and sum the results.
+#[cfg(feature = "simplex")]
+fn integrate_simplices<X: Scalar>(
+    region: &RegionDescriptor<X>,
+    rule: &simplex::SimplexRule,
+    function: &dyn Fn(&[X]) -> X,
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    let simplices = simplices(region)?;
+    let points = simplices.iter().flat_map(|vertices| {
+        let volume = simplex::volume(vertices);
+        rule.iter().map(move |(barycentric, weight)| {
+            (
+                simplex::point(vertices, barycentric),
+                volume * X::from(*weight).unwrap(),
+            )
+        })
+    });
+    weighted_sum(points, function, policy, summation)
+}
+
+/// Apply a rule of the unit sphere over the sphere region.
+#[cfg(feature = "polar")]
+fn integrate_sphere<X: Scalar>(
+    region: &RegionDescriptor<X>,
+    rule: Vec<([X; 3], X)>,
+    function: &dyn Fn(&[X]) -> X,
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    let RegionDescriptor::Sphere { center, radius } = region else {
+        unreachable!()
+    };
+    let points = rule.into_iter().map(|(u, weight)| {
+        (
+            (0..3).map(|k| center[k] + *radius * u[k]).collect(),
+            *radius * *radius * weight,
+        )
+    });
+    weighted_sum(points, function, policy, summation)
+}
@@ -0,0 +1,87 @@
+//! curve integration code
+
+// ------ IMPORTS
+
+use super::validation;
+use crate::{
+    ComputeMethod, CurveDescriptor, DomainDescriptor, FunctionDescriptor, IntegraalError, Scalar,
+};
+
+// ------ CONTENT
+
+/// Check that the curve fits the requirements of the method.
+pub(crate) fn validate<X: Scalar>(
+    curve: &CurveDescriptor<X>,
+    method: ComputeMethod,
+    allow_descending: bool,
+) -> Result<(), IntegraalError> {
+    match curve {
+        CurveDescriptor::Parametric { parameter, .. } => {
+            validation::validate(parameter, method, allow_descending)?;
+        }
+        CurveDescriptor::Polyline { vertices, n_sample } => {
+            if vertices.len() < 2 {
+                return Err(IntegraalError::BadParameters(format!(
+                    "a polyline should have at least 2 vertices (vertices: {})",
+                    vertices.len()
+                )));
+            }
+            let dim = vertices[0].len();
+            if let Some(idx) = vertices.iter().position(|v| v.is_empty() || v.len() != dim) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "polyline vertices should have the same, non-zero number of coordinates (vertex {idx}: {}, vertex 0: {dim})",
+                    vertices[idx].len()
+                )));
+            }
+            if vertices.iter().flatten().any(|x| !x.is_finite()) {
+                return Err(IntegraalError::BadParameters(String::from(
+                    "polyline vertices should have finite coordinates",
+                )));
+            }
+            validation::validate(&segment_parameter::<X>(*n_sample), method, false)?;
+        }
+    }
+    Ok(())
+}
+
+/// Return the domain of the parameter over each segment of a polyline.
+pub(crate) fn segment_parameter<X: Scalar>(n_sample: usize) -> DomainDescriptor<X> {
+    DomainDescriptor::linspace(X::zero(), X::one(), n_sample)
+}
+
+/// Approximate the derivative of the curve at `t` using central finite differences.
+///
+/// The step is scaled so that truncation & rounding errors are balanced.
+pub(crate) fn tangent<X: Scalar>(point: &dyn Fn(X) -> Vec<X>, t: X) -> Vec<X> {
+    let h = X::epsilon().cbrt() * t.abs().max(X::one());
+    let (before, after) = (point(t - h), point(t + h));
+    after
+        .iter()
+        .zip(&before)
+        .map(|(a, b)| (*a - *b) / (h + h))
+        .collect()
+}
+
+/// Return the integrand of the line integral of the function, at a point of the curve where the
+/// derivative of the curve is `tangent`.
+pub(crate) fn line_element<X: Scalar>(
+    function: &FunctionDescriptor<X>,
+    point: &[X],
+    tangent: &[X],
+) -> X {
+    match function {
+        FunctionDescriptor::MultiClosure(closure) => {
+            closure(point)
+                * tangent
+                    .iter()
+                    .fold(X::zero(), |acc, d| acc + *d * *d)
+                    .sqrt()
+        }
+        FunctionDescriptor::VectorField(field) => field(point)
+            .iter()
+            .zip(tangent)
+            .fold(X::zero(), |acc, (f, d)| acc + *f * *d),
+        // rejected before computation
+        _ => unreachable!(),
+    }
+}
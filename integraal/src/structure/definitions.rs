@@ -2,25 +2,80 @@
 
 // ------ IMPORTS
 
-use crate::{ComputeMethod, DomainDescriptor, FunctionDescriptor, Scalar};
+use crate::{
+    ComputeMethod, DiscontinuityDetection, DomainDescriptor, FunctionDescriptor, NonFinitePolicy,
+    RegionDescriptor, Scalar, Summation,
+};
 
 // ------ CONTENT
 
 /// Integral error
-#[derive(Debug, Copy, Clone, PartialEq, thiserror::Error)]
+///
+/// Variants carry enough context to identify the faulty parameter, e.g. which lengths mismatched
+/// or which method was requested.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum IntegraalError {
     /// Some parameters do not fit the requirements of the computation method.
     #[error("{0}")]
-    BadParameters(&'static str),
+    BadParameters(String),
     /// Specified parameters are conflicting or ambiguous.
     #[error("{0}")]
-    InconsistentParameters(&'static str),
+    InconsistentParameters(String),
+    /// Function and domain descriptors do not describe the same number of samples.
+    #[error("function and domain descriptors have different lengths (domain: {domain}, function: {function})")]
+    LengthMismatch {
+        /// Number of samples of the domain.
+        domain: usize,
+        /// Number of samples of the function.
+        function: usize,
+    },
     /// One or more parameters are missing.
-    #[error("{0}")]
-    MissingParameters(&'static str),
+    #[error("missing parameter(s): {}", .0.join(", "))]
+    MissingParameters(Vec<&'static str>),
+    /// The domain does not contain enough samples for the requested method.
+    #[error("{} requires at least {required} samples, got {got}", .method.name())]
+    TooFewSamples {
+        /// Requested method.
+        method: ComputeMethod,
+        /// Minimum number of samples required by the method.
+        required: usize,
+        /// Number of samples of the domain.
+        got: usize,
+    },
+    /// A method did not reach the requested accuracy.
+    #[error("computation did not converge (achieved error: {achieved_error:e}, evaluations: {evaluations})")]
+    NotConverged {
+        /// Best error estimate achieved.
+        achieved_error: f64,
+        /// Number of function evaluations performed.
+        evaluations: usize,
+    },
     /// A given method isn't implemented for the specified parameters (e.g. due to requirements).
-    #[error("{0}")]
-    Unimplemented(&'static str),
+    #[error("{} isn't implemented for {context}", .method.name())]
+    Unimplemented {
+        /// Requested method.
+        method: ComputeMethod,
+        /// Description of the unsupported parameters.
+        context: &'static str,
+    },
+    /// A non-finite function value was encountered (see [`NonFinitePolicy::Error`]).
+    #[error("non-finite function value at sample {index} (x = {abscissa})")]
+    NonFinite {
+        /// Index of the faulty sample in the domain; if the function was evaluated between
+        /// samples, this is the index of the preceding sample.
+        index: usize,
+        /// Value of the variable at which the function was evaluated.
+        abscissa: f64,
+    },
+    /// A non-finite function value was encountered at a point of a cubature rule (see
+    /// [`NonFinitePolicy::Error`]).
+    #[error("non-finite function value at point {index} of the rule (x = {point:?})")]
+    NonFinitePoint {
+        /// Index of the faulty point in the rule.
+        index: usize,
+        /// Coordinates of the point.
+        point: Vec<f64>,
+    },
 }
 
 /// Main integral computation structure
@@ -28,7 +83,11 @@ pub enum IntegraalError {
 /// This structure is used as the entrypoint for integral definition and computation. It follows
 /// a pseudo-builder patterns where the function description is reset after a computation. This is
 /// the preferred behavior as many different integrals may be computed over the same domain in
-/// scientific applications.
+/// scientific applications. The same integral can still be computed multiple times using
+/// [`Integraal::evaluate`] or [`Integraal::compute_all`], which leave the structure untouched.
+///
+/// A type-state builder, checking components at compile time and method requirements before
+/// computation, is also available using [`Integraal::builder`].
 ///
 /// # Usage
 ///
@@ -36,11 +95,24 @@ pub enum IntegraalError {
 ///
 /// The structure is made up of three components that are used to describe the integral the user
 /// wishes to compute:
-/// - a [`DomainDescriptor`] instance, used to describe the space over which the integral span
+/// - a [`DomainDescriptor`] instance, used to describe the space over which the integral span;
+///   multi-dimensional spaces are described using a [`RegionDescriptor`] instead (see
+///   [`Integraal::region`])
 /// - a [`FunctionDescriptor`] instance, used to describe the integrated function
 /// - a [`ComputeMethod`] instance, used to choose which numerical integration method will be used
 ///   for value approximation
 ///
+/// Optional settings can also be adjusted; default values are used otherwise:
+/// - a [`NonFinitePolicy`] instance, used to choose how `NaN` or infinite function values are
+///   handled during computation
+/// - a boolean flag, used to accept domains described in decreasing order (see
+///   [`Integraal::allow_descending`])
+/// - a boolean flag, used to signal that the function is periodic over the domain (see
+///   [`Integraal::periodic`])
+/// - a [`Summation`] instance, used to choose how contributions of samples are accumulated
+/// - a [`DiscontinuityDetection`] instance, used to split integrals of values at detected
+///   discontinuities (see [`Integraal::detect_discontinuities`])
+///
 /// In the future, another object might be included to control the execution backend.
 ///
 /// ## Example
@@ -74,8 +146,118 @@ pub enum IntegraalError {
 pub struct Integraal<X: Scalar> {
     /// Domain over which the function is integrated.
     pub(crate) domain: Option<DomainDescriptor<X>>,
+    /// Multi-dimensional region over which the function is integrated.
+    pub(crate) region: Option<RegionDescriptor<X>>,
     /// Function to integrate.
     pub(crate) function: Option<FunctionDescriptor<X>>,
     /// Numerical integration method used for value approximation.
     pub(crate) method: Option<ComputeMethod>,
+    /// Handling policy of non-finite function values.
+    pub(crate) non_finite: NonFinitePolicy,
+    /// Accept domains described in decreasing order.
+    pub(crate) allow_descending: bool,
+    /// Integrate the function as periodic over the domain.
+    pub(crate) periodic: bool,
+    /// Summation strategy used by computation methods.
+    pub(crate) summation: Summation,
+    /// Discontinuity detection settings, if enabled.
+    pub(crate) detection: Option<DiscontinuityDetection>,
+}
+
+/// Discontinuity kind enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Discontinuity {
+    /// Discontinuity of the function.
+    Jump,
+    /// Discontinuity of the derivative of the function.
+    Kink,
+}
+
+/// Detected discontinuity
+///
+/// Sampled data only locates discontinuities up to the resolution of the domain: the
+/// discontinuity lies somewhere in `[start, end]`, and `start == end` if it was located on a
+/// sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Breakpoint<X: Scalar> {
+    /// Kind of discontinuity.
+    pub kind: Discontinuity,
+    /// Lower bound of the location of the discontinuity.
+    pub start: X,
+    /// Upper bound of the location of the discontinuity.
+    pub end: X,
+}
+
+/// Detailed computation result
+///
+/// This structure is returned by [`Integraal::evaluate_detailed`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation<X: Scalar> {
+    /// Computed value of the integral.
+    pub value: X,
+    /// Discontinuities detected in the function values, in increasing order; this is empty if
+    /// detection is disabled.
+    pub breakpoints: Vec<Breakpoint<X>>,
+    /// Estimate of the absolute error of the value, if available; this is only computed for
+    /// periodic functions (see [`Integraal::periodic`]), and by methods that provide an error
+    /// indicator, e.g. sparse grids.
+    pub error: Option<X>,
+    /// Number of function evaluations, if counted; this is only done by methods that choose
+    /// their points adaptively, or share them between levels, e.g. sparse grids.
+    pub evaluations: Option<usize>,
+}
+
+impl<X: Scalar> From<X> for Evaluation<X> {
+    fn from(value: X) -> Self {
+        Self {
+            value,
+            breakpoints: Vec::new(),
+            error: None,
+            evaluations: None,
+        }
+    }
+}
+
+/// Method comparison structure
+///
+/// This structure holds the results of the same integral computed using different methods. It is
+/// returned by [`Integraal::compute_all`], and can be printed as a table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison<X: Scalar> {
+    /// Computation results, in the order methods were specified.
+    pub results: Vec<(ComputeMethod, Result<X, IntegraalError>)>,
+}
+
+impl<X: Scalar> Comparison<X> {
+    /// Return the result of the first computation using the specified method, if any.
+    #[must_use]
+    pub fn get(&self, method: &ComputeMethod) -> Option<&Result<X, IntegraalError>> {
+        self.results
+            .iter()
+            .find_map(|(m, res)| (m == method).then_some(res))
+    }
+
+    /// Return the difference between the largest and smallest successfully computed values.
+    ///
+    /// This is a crude indicator of the agreement between methods; `None` is returned if no
+    /// computation succeeded.
+    #[must_use]
+    pub fn spread(&self) -> Option<X> {
+        let mut values = self.results.iter().filter_map(|(_, res)| res.as_ref().ok());
+        let first = *values.next()?;
+        let (min, max) = values.fold((first, first), |(min, max), v| (min.min(*v), max.max(*v)));
+        Some(max - min)
+    }
+}
+
+impl<X: Scalar> std::fmt::Display for Comparison<X> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (method, res) in &self.results {
+            match res {
+                Ok(value) => writeln!(f, "{:<32} {value:?}", method.name())?,
+                Err(e) => writeln!(f, "{:<32} error: {e}", method.name())?,
+            }
+        }
+        Ok(())
+    }
 }
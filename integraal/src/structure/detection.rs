@@ -0,0 +1,120 @@
+//! discontinuity detection code
+
+// ------ IMPORTS
+
+use crate::{Discontinuity, DiscontinuityDetection, DomainDescriptor, Scalar};
+
+// ------ CONTENT
+
+/// Discontinuity located between the `lo`-th and `hi`-th samples (included) of a domain.
+///
+/// `lo == hi` if the discontinuity is located exactly on a sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Feature {
+    pub(crate) kind: Discontinuity,
+    pub(crate) lo: usize,
+    pub(crate) hi: usize,
+}
+
+/// Detect jumps & kinks of sampled values, using divided differences.
+///
+/// A segment contains a jump if its first difference dominates the ones of its neighbouring
+/// segments; a sample is a kink if the change of slope at this point dominates the ones of
+/// samples two positions away (so that kinks located inside a segment, which spread over both
+/// ends of the segment, are also detected). Differences below `sqrt(epsilon)` times the scale of
+/// the data are ignored. Non-finite values are never part of a detected feature.
+///
+/// Features are returned in increasing order and do not overlap.
+pub(crate) fn detect<X: Scalar>(
+    vals: &[X],
+    domain: &DomainDescriptor<X>,
+    settings: &DiscontinuityDetection,
+) -> Vec<Feature> {
+    let n_sample = vals.len();
+    if n_sample < 3 {
+        return Vec::new();
+    }
+    let tolerance = X::epsilon().sqrt();
+    let jump_ratio = X::from(settings.jump_ratio).unwrap();
+    let kink_ratio = X::from(settings.kink_ratio).unwrap();
+
+    // jumps
+    let diffs: Vec<X> = vals.windows(2).map(|w| w[1] - w[0]).collect();
+    let scale = max_abs(vals.iter().copied());
+    let jumps: Vec<usize> = (0..diffs.len())
+        .filter(|&i| {
+            let d = diffs[i].abs();
+            d > tolerance * scale
+                && neighbours(&diffs, i, 1).is_some_and(|neighbour| d > jump_ratio * neighbour)
+        })
+        .collect();
+    let near_jump = |i: usize| jumps.contains(&i) || i > 0 && jumps.contains(&(i - 1));
+
+    // kinks; `changes[i]` is the change of slope at the `i+1`-th sample
+    let slopes: Vec<X> = (0..diffs.len())
+        .map(|i| diffs[i] / (domain.abscissa(i + 1) - domain.abscissa(i)))
+        .collect();
+    let slope_scale = max_abs(
+        (0..slopes.len())
+            .filter(|i| !jumps.contains(i))
+            .map(|i| slopes[i]),
+    );
+    let changes: Vec<X> = slopes.windows(2).map(|w| w[1] - w[0]).collect();
+    let kinks: Vec<usize> = (0..changes.len())
+        .filter(|&i| {
+            let c = changes[i].abs();
+            !near_jump(i + 1)
+                && c > tolerance * slope_scale
+                && neighbours(&changes, i, 2).is_some_and(|neighbour| c > kink_ratio * neighbour)
+        })
+        .map(|i| i + 1)
+        .collect();
+
+    // consecutive kink samples are merged into a single feature
+    let mut features: Vec<Feature> = jumps
+        .iter()
+        .map(|&i| Feature {
+            kind: Discontinuity::Jump,
+            lo: i,
+            hi: i + 1,
+        })
+        .collect();
+    for i in kinks {
+        match features.last_mut() {
+            Some(last) if last.kind == Discontinuity::Kink && last.hi + 1 == i => last.hi = i,
+            _ => features.push(Feature {
+                kind: Discontinuity::Kink,
+                lo: i,
+                hi: i,
+            }),
+        }
+    }
+    features.sort_by_key(|feature| (feature.lo, feature.hi));
+    let mut end = 0;
+    features.retain(|feature| {
+        let keep = feature.lo >= end;
+        if keep {
+            end = feature.hi;
+        }
+        keep
+    });
+    features
+}
+
+/// Return the largest finite absolute value of the items.
+fn max_abs<X: Scalar>(items: impl Iterator<Item = X>) -> X {
+    items
+        .filter(|v| v.is_finite())
+        .fold(X::zero(), |acc, v| acc.max(v.abs()))
+}
+
+/// Return the largest absolute value of the items located `gap` positions away from the
+/// `idx`-th one, if any.
+fn neighbours<X: Scalar>(items: &[X], idx: usize, gap: usize) -> Option<X> {
+    let before = idx.checked_sub(gap).map(|i| items[i].abs());
+    let after = (idx + gap < items.len()).then(|| items[idx + gap].abs());
+    match (before, after) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
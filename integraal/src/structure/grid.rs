@@ -0,0 +1,115 @@
+//! uniform grid code
+
+// ------ IMPORTS
+
+use crate::{DomainDescriptor, Scalar};
+
+// ------ CONTENT
+
+/// Uniform discretization of an interval.
+///
+/// This structure owns the conventions used by all methods working on uniform domains:
+/// - the grid is made up of `n_sample` points, i.e. `n_sample - 1` segments
+/// - the first and last points are the limits of the integral
+/// - the `i`-th point is computed directly from the start of the grid, so that no error
+///   accumulates along the grid; the last point is always the exact end of the interval
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct UniformGrid<X: Scalar> {
+    start: X,
+    end: X,
+    step: X,
+    n_sample: usize,
+}
+
+impl<X: Scalar> UniformGrid<X> {
+    /// Build a grid from its first point, its step and its number of points.
+    pub(crate) fn new(start: X, step: X, n_sample: usize) -> Self {
+        Self {
+            start,
+            end: start + step * X::from(n_sample.saturating_sub(1)).unwrap(),
+            step,
+            n_sample,
+        }
+    }
+
+    /// Build a grid of `n_sample` points from its exact endpoints.
+    pub(crate) fn linspace(start: X, end: X, n_sample: usize) -> Self {
+        Self {
+            start,
+            end,
+            step: (end - start) / X::from(n_sample.saturating_sub(1).max(1)).unwrap(),
+            n_sample,
+        }
+    }
+
+    /// Build the grid corresponding to a uniform domain.
+    pub(crate) fn from_domain(domain: &DomainDescriptor<X>) -> Option<Self> {
+        match domain {
+            DomainDescriptor::Uniform {
+                start,
+                step,
+                n_step,
+            } => Some(Self::new(*start, *step, *n_step)),
+            DomainDescriptor::Explicit(_)
+            | DomainDescriptor::Generated(_)
+            | DomainDescriptor::Piecewise(_) => None,
+        }
+    }
+
+    /// Return the first point of the grid.
+    pub(crate) fn start(&self) -> X {
+        self.start
+    }
+
+    /// Return the last point of the grid.
+    #[cfg(any(feature = "filon", feature = "richardson", feature = "romberg"))]
+    pub(crate) fn end(&self) -> X {
+        self.end
+    }
+
+    /// Return the step between two consecutive points.
+    pub(crate) fn step(&self) -> X {
+        self.step
+    }
+
+    /// Return the number of points of the grid.
+    pub(crate) fn n_sample(&self) -> usize {
+        self.n_sample
+    }
+
+    /// Return the number of segments of the grid.
+    #[cfg(any(feature = "filon", feature = "romberg"))]
+    pub(crate) fn n_segment(&self) -> usize {
+        self.n_sample.saturating_sub(1)
+    }
+
+    /// Return the length of the covered interval.
+    #[cfg(any(feature = "montecarlo", feature = "richardson", feature = "romberg"))]
+    pub(crate) fn length(&self) -> X {
+        self.end - self.start
+    }
+
+    /// Return the `idx`-th point of the grid.
+    pub(crate) fn node(&self, idx: usize) -> X {
+        if idx + 1 == self.n_sample {
+            self.end
+        } else {
+            self.start + self.step * X::from(idx).unwrap()
+        }
+    }
+
+    /// Return the point located at the `num / den` fraction of the interval.
+    #[cfg(any(feature = "richardson", feature = "romberg"))]
+    pub(crate) fn at_fraction(&self, num: usize, den: usize) -> X {
+        if num == den {
+            self.end
+        } else {
+            self.start + self.length() * X::from(num).unwrap() / X::from(den).unwrap()
+        }
+    }
+
+    /// Return the same grid, described from its last point to its first.
+    pub(crate) fn reversed(&self) -> Self {
+        Self::linspace(self.end, self.start, self.n_sample)
+    }
+}
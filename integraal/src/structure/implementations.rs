@@ -2,11 +2,26 @@
 
 // ------ IMPORTS
 
+#[cfg(feature = "adaptive")]
+use super::adaptive;
+#[cfg(feature = "singular")]
+use super::singular;
+#[cfg(feature = "sparse")]
+use super::sparse;
+use super::{
+    cubature, curve, detection, grid::UniformGrid, iterated, nodes::Nodes, non_finite, oscillatory,
+    scattered, summation::SumWith, surface, validation,
+};
+#[cfg(feature = "richardson")]
+use crate::BaseRule;
 use crate::{
-    ComputeMethod, DomainDescriptor, FunctionDescriptor, Integraal, IntegraalError, Scalar,
+    Breakpoint, Comparison, ComputeMethod, CurveDescriptor, DiscontinuityDetection,
+    DomainDescriptor, Evaluation, FunctionDescriptor, Integraal, IntegraalError, IteratedLevel,
+    NonFinitePolicy, Oscillator, Piece, RegionDescriptor, Scalar, Summation, SurfaceDescriptor,
 };
 #[cfg(feature = "montecarlo")]
 use rand::Rng;
+use std::cell::{Cell, OnceCell};
 
 // ------ CONTENT
 
@@ -18,6 +33,16 @@ impl<X: Scalar> Integraal<X> {
         self
     }
 
+    /// Set the region descriptor, to integrate over a multi-dimensional space.
+    ///
+    /// The function should then be described using [`FunctionDescriptor::MultiClosure`], and the
+    /// domain descriptor should not be set.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn region(mut self, region_descriptor: RegionDescriptor<X>) -> Self {
+        self.region = Some(region_descriptor);
+        self
+    }
+
     /// Set the function descriptor.
     #[must_use = "unused builder struct - please remove this call"]
     pub fn function(mut self, function_descriptor: FunctionDescriptor<X>) -> Self {
@@ -32,134 +57,1241 @@ impl<X: Scalar> Integraal<X> {
         self
     }
 
-    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    /// Allow domains described in decreasing order.
+    ///
+    /// If enabled, explicit domains sorted in strictly decreasing order and uniform domains with a
+    /// negative step are accepted; the integral is then computed over the increasing domain and
+    /// its sign is flipped, as per the usual convention. By default, such domains are rejected.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn allow_descending(mut self, allow: bool) -> Self {
+        self.allow_descending = allow;
+        self
+    }
+
+    /// Integrate the function as periodic over the domain.
+    ///
+    /// If enabled, the domain must be uniform and describe a single period without its end
+    /// point, i.e. the period is `n_step * step`. The trapezoid rule then uses equal weights, and
+    /// the rectangle rules coincide with it; for smooth periodic functions, the error decreases
+    /// exponentially with the number of samples. If this number is even, an estimate of the error
+    /// is computed using every other sample -- see [`Evaluation::error`]. Discontinuity detection
+    /// is not applied. By default, functions are not assumed to be periodic.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn periodic(mut self, periodic: bool) -> Self {
+        self.periodic = periodic;
+        self
+    }
+
+    /// Set the summation strategy used by computation methods.
+    ///
+    /// By default, contributions are summed naively.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn summation(mut self, strategy: Summation) -> Self {
+        self.summation = strategy;
+        self
+    }
+
+    /// Set the handling policy of non-finite function values.
+    ///
+    /// By default, the computation fails on the first non-finite value encountered.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite = policy;
+        self
+    }
+
+    /// Enable discontinuity detection, using the specified settings.
+    ///
+    /// Function values are then split at detected jumps and kinks before computation -- see
+    /// [`DiscontinuityDetection`]. Detected discontinuities can be retrieved using
+    /// [`Integraal::evaluate_detailed`]. By default, detection is disabled.
+    #[must_use = "unused builder struct - please remove this call"]
+    pub fn detect_discontinuities(mut self, settings: DiscontinuityDetection) -> Self {
+        self.detection = Some(settings);
+        self
+    }
+
+    #[allow(clippy::missing_errors_doc)]
     /// This method attempts to compute the integral. If it is successful, it will clear the
     /// internal [`FunctionDescriptor`] object before returning the result.
     ///
+    /// Use [`Integraal::evaluate`] to keep the function descriptor for further computations.
+    ///
     /// # Return / Errors
     ///
     /// This method returns a `Result` taking the following values:
     /// - `Ok(X: Scalar)` -- The computation succeeded.
     /// - `Err(IntegraalError)` -- The computation failed for the reason specified by the enum.
     pub fn compute(&mut self) -> Result<X, IntegraalError> {
-        // ensure all data is defined; evaluate function first because it is reset after all computations
-        if self.function.is_none() | self.domain.is_none() | self.method.is_none() {
-            return Err(IntegraalError::MissingParameters(
-                "one or more parameter is missing",
-            ));
-        }
+        let res = self.evaluate()?;
+        self.function = None; // is this really useful? we could directly return if this wasn't here
+        Ok(res)
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    /// This method attempts to compute the integral, leaving the structure untouched.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method returns a `Result` taking the following values:
+    /// - `Ok(X: Scalar)` -- The computation succeeded.
+    /// - `Err(IntegraalError)` -- The computation failed for the reason specified by the enum.
+    pub fn evaluate(&self) -> Result<X, IntegraalError> {
+        self.evaluate_detailed().map(|evaluation| evaluation.value)
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    /// This method attempts to compute the integral, leaving the structure untouched, and
+    /// returns details about the computation along with its result.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method returns a `Result` taking the following values:
+    /// - `Ok(Evaluation<X>)` -- The computation succeeded.
+    /// - `Err(IntegraalError)` -- The computation failed for the reason specified by the enum.
+    pub fn evaluate_detailed(&self) -> Result<Evaluation<X>, IntegraalError> {
+        // ensure all data is defined
+        self.check_missing(true)?;
 
-        let Some(method) = &self.method else {
+        let (Some(function), Some(method)) = (&self.function, &self.method) else {
             unreachable!()
         };
-
+        if let Some(region) = &self.region {
+            return self.integrate_region(region, function, *method);
+        }
         let Some(domain) = &self.domain else {
             unreachable!()
         };
 
-        #[rustfmt::skip]
-        let res = match (&self.function, &self.domain) {
-            // function descriptor -- values
-            // domain descriptor   -- explicit
+        self.integrate(domain, function, *method, None)
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    /// This method attempts to compute the integral of a vector-valued function (see
+    /// [`FunctionDescriptor::VectorField`]) over a hyperrectangle, component-wise, leaving the
+    /// structure untouched.
+    ///
+    /// This is only supported by adaptive methods: subdivisions of the region are shared by all
+    /// components, and the computation stops once all of them reach the tolerance.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method returns a `Result` taking the following values:
+    /// - `Ok(Vec<Evaluation<X>>)` -- The computation succeeded; there is one evaluation per
+    ///   component.
+    /// - `Err(IntegraalError)` -- The computation failed for the reason specified by the enum.
+    pub fn evaluate_vector(&self) -> Result<Vec<Evaluation<X>>, IntegraalError> {
+        // ensure all data is defined
+        self.check_missing(true)?;
+
+        let (Some(function), Some(method)) = (&self.function, &self.method) else {
+            unreachable!()
+        };
+        let (
+            Some(region @ RegionDescriptor::Hyperrectangle(_)),
+            FunctionDescriptor::VectorField(_),
+        ) = (&self.region, function)
+        else {
+            return Err(IntegraalError::InconsistentParameters(String::from(
+                "component-wise integration requires a vector field (`FunctionDescriptor::VectorField`) over a hyperrectangle (`RegionDescriptor::Hyperrectangle`)",
+            )));
+        };
+        cubature::validate(region, *method, self.allow_descending)?;
+        match (method, region, function) {
+            #[cfg(feature = "adaptive")]
             (
-                Some(FunctionDescriptor::Values(vals)),
-                Some(DomainDescriptor::Explicit(args))
-            ) => values_explicit_arm(vals, args, method)?,
-            // function descriptor -- values
-            // domain descriptor   -- uniform
+                ComputeMethod::GenzMalik {
+                    abs_tolerance,
+                    rel_tolerance,
+                    max_evaluations,
+                },
+                RegionDescriptor::Hyperrectangle(bounds),
+                FunctionDescriptor::VectorField(field),
+            ) => adaptive::genz_malik(
+                bounds,
+                field.as_ref(),
+                (*abs_tolerance, *rel_tolerance, *max_evaluations),
+                self.non_finite,
+                self.summation,
+            ),
+            _ => Err(IntegraalError::Unimplemented {
+                method: *method,
+                context: "vector-valued functions",
+            }),
+        }
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    /// This method computes the integral using each of the specified methods, leaving the
+    /// structure untouched. The method set using [`Integraal::method`] is ignored.
+    ///
+    /// If the function is described using a closure, its values over the domain are computed
+    /// once and shared by all methods that only sample the function at domain points.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method returns a `Result` taking the following values:
+    /// - `Ok(Comparison<X>)` -- The result of each computation, in the order of `methods`.
+    /// - `Err(IntegraalError)` -- The domain or function descriptor is missing.
+    pub fn compute_all(&self, methods: &[ComputeMethod]) -> Result<Comparison<X>, IntegraalError> {
+        // ensure all data is defined
+        self.check_missing(false)?;
+
+        let Some(function) = &self.function else {
+            unreachable!()
+        };
+
+        let cache = OnceCell::new();
+        let results = methods
+            .iter()
+            .map(|method| {
+                let res = match (&self.region, &self.domain) {
+                    (Some(region), _) => self
+                        .integrate_region(region, function, *method)
+                        .map(|evaluation| evaluation.value),
+                    (None, Some(domain)) => self
+                        .integrate(domain, function, *method, Some(&cache))
+                        .map(|evaluation| evaluation.value),
+                    (None, None) => unreachable!(),
+                };
+                (*method, res)
+            })
+            .collect();
+
+        Ok(Comparison { results })
+    }
+
+    /// Return an error listing missing components, if any.
+    fn check_missing(&self, with_method: bool) -> Result<(), IntegraalError> {
+        if self.domain.is_some() && self.region.is_some() {
+            return Err(IntegraalError::InconsistentParameters(String::from(
+                "both a domain and a region were specified",
+            )));
+        }
+        let missing: Vec<&'static str> = [
+            ("domain", self.domain.is_none() && self.region.is_none()),
+            ("function", self.function.is_none()),
+            ("method", with_method && self.method.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(name, is_missing)| is_missing.then_some(name))
+        .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(IntegraalError::MissingParameters(missing))
+        }
+    }
+
+    /// Compute the integral over a multi-dimensional region.
+    fn integrate_region(
+        &self,
+        region: &RegionDescriptor<X>,
+        function: &FunctionDescriptor<X>,
+        method: ComputeMethod,
+    ) -> Result<Evaluation<X>, IntegraalError> {
+        cubature::check_function(region, function)?;
+        cubature::validate(region, method, self.allow_descending)?;
+        // sparse grids also provide an error indicator
+        #[cfg(feature = "sparse")]
+        if let (
+            ComputeMethod::Smolyak {
+                rule,
+                level,
+                tolerance,
+            },
+            RegionDescriptor::Hyperrectangle(bounds),
+            FunctionDescriptor::MultiClosure(closure),
+        ) = (method, region, function)
+        {
+            return sparse::smolyak(
+                bounds,
+                closure.as_ref(),
+                (rule, level, tolerance),
+                self.non_finite,
+                self.summation,
+            );
+        }
+        #[cfg(feature = "adaptive")]
+        if let (
+            ComputeMethod::GenzMalik {
+                abs_tolerance,
+                rel_tolerance,
+                max_evaluations,
+            },
+            RegionDescriptor::Hyperrectangle(bounds),
+            FunctionDescriptor::MultiClosure(closure),
+        ) = (method, region, function)
+        {
+            let components = |x: &[X]| vec![closure(x)];
+            return adaptive::genz_malik(
+                bounds,
+                &components,
+                (abs_tolerance, rel_tolerance, max_evaluations),
+                self.non_finite,
+                self.summation,
+            )
+            .map(|mut evaluations| evaluations.swap_remove(0));
+        }
+        self.integrate_region_value(region, function, method)
+            .map(Evaluation::from)
+    }
+
+    /// Compute the value of the integral over a multi-dimensional region, which is expected to
+    /// be valid for the method.
+    fn integrate_region_value(
+        &self,
+        region: &RegionDescriptor<X>,
+        function: &FunctionDescriptor<X>,
+        method: ComputeMethod,
+    ) -> Result<X, IntegraalError> {
+        if let RegionDescriptor::Curve(curve) = region {
+            return self.integrate_curve(curve, function, method);
+        }
+        if let RegionDescriptor::Surface(surface) = region {
+            return self.integrate_surface(surface, function, method);
+        }
+        if let (
+            RegionDescriptor::Grid {
+                shape,
+                spacing,
+                mask,
+            },
+            FunctionDescriptor::Values(vals),
+        ) = (region, function)
+        {
+            return self.integrate_grid(vals, shape, spacing, mask.as_deref(), method);
+        }
+        if let (RegionDescriptor::Scattered { points, clip }, FunctionDescriptor::Values(vals)) =
+            (region, function)
+        {
+            return scattered::integrate(
+                points,
+                clip.as_deref(),
+                vals,
+                self.non_finite,
+                self.summation,
+            );
+        }
+        let FunctionDescriptor::MultiClosure(closure) = function else {
+            unreachable!()
+        };
+        if let RegionDescriptor::Iterated(levels) = region {
+            return self.integrate_iterated(levels, closure.as_ref(), method, &[]);
+        }
+        #[cfg(any(feature = "polar", feature = "simplex"))]
+        return cubature::integrate(
+            region,
+            closure.as_ref(),
+            method,
+            self.non_finite,
+            self.summation,
+        );
+        // other regions require one of the methods above, and are rejected by validation otherwise
+        #[cfg(not(any(feature = "polar", feature = "simplex")))]
+        unreachable!()
+    }
+
+    /// Compute the line integral of the function along a curve, using the method over its
+    /// parameter.
+    fn integrate_curve(
+        &self,
+        curve: &CurveDescriptor<X>,
+        function: &FunctionDescriptor<X>,
+        method: ComputeMethod,
+    ) -> Result<X, IntegraalError> {
+        match curve {
+            CurveDescriptor::Parametric {
+                point,
+                derivative,
+                parameter,
+            } => {
+                let integrand = |t: X| {
+                    let tangent = derivative
+                        .as_ref()
+                        .map_or_else(|| curve::tangent(point.as_ref(), t), |d| d(t));
+                    curve::line_element(function, &point(t), &tangent)
+                };
+                if let DomainDescriptor::Piecewise(pieces) = parameter {
+                    let results = pieces
+                        .iter()
+                        .map(|piece| {
+                            let method = piece.method.as_ref().unwrap_or(&method);
+                            self.integrate_samples(
+                                &piece.domain,
+                                Samples::Closure(&integrand),
+                                *method,
+                            )
+                            .map(|evaluation| evaluation.value)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    return Ok(results.into_iter().sum_with(self.summation));
+                }
+                self.integrate_samples(parameter, Samples::Closure(&integrand), method)
+                    .map(|evaluation| evaluation.value)
+            }
+            CurveDescriptor::Polyline { vertices, n_sample } => {
+                let parameter = curve::segment_parameter(*n_sample);
+                let results = vertices
+                    .windows(2)
+                    .map(|segment| {
+                        let tangent: Vec<X> = segment[1]
+                            .iter()
+                            .zip(&segment[0])
+                            .map(|(b, a)| *b - *a)
+                            .collect();
+                        let integrand = |t: X| {
+                            let point: Vec<X> = segment[0]
+                                .iter()
+                                .zip(&tangent)
+                                .map(|(a, d)| *a + t * *d)
+                                .collect();
+                            curve::line_element(function, &point, &tangent)
+                        };
+                        self.integrate_samples(&parameter, Samples::Closure(&integrand), method)
+                            .map(|evaluation| evaluation.value)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(results.into_iter().sum_with(self.summation))
+            }
+        }
+    }
+
+    /// Compute the surface integral of the function, using the tensor product of the method over
+    /// the parameters of the surface.
+    fn integrate_surface(
+        &self,
+        surface: &SurfaceDescriptor<X>,
+        function: &FunctionDescriptor<X>,
+        method: ComputeMethod,
+    ) -> Result<X, IntegraalError> {
+        let (u, v) = (&surface.u, &surface.v);
+        if let FunctionDescriptor::Values(vals) = function {
+            let n_u = u.n_sample();
+            let inner = vals
+                .chunks(n_u)
+                .enumerate()
+                .map(|(j, row)| {
+                    let t = v.abscissa(j);
+                    let row: Vec<X> = row
+                        .iter()
+                        .enumerate()
+                        .map(|(i, val)| {
+                            let (ru, rv) = surface::partials(surface, u.abscissa(i), t);
+                            *val * surface::area_element(&ru, &rv)
+                        })
+                        .collect();
+                    self.integrate_samples(u, Samples::Values(&row, false), method)
+                        .map(|evaluation| evaluation.value)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            return self
+                .integrate_samples(v, Samples::Values(&inner, false), method)
+                .map(|evaluation| evaluation.value);
+        }
+
+        // inner integrals cannot return errors through the outer closure; the first one is kept
+        let error = Cell::new(None);
+        let outer = |t: X| {
+            let inner = |s: X| surface::element(surface, function, s, t);
+            self.integrate_samples(u, Samples::Closure(&inner), method)
+                .map_or_else(
+                    |err| {
+                        let first = error.take().unwrap_or(err);
+                        error.set(Some(first));
+                        X::zero()
+                    },
+                    |evaluation| evaluation.value,
+                )
+        };
+        let res = self.integrate_samples(v, Samples::Closure(&outer), method);
+        match error.into_inner() {
+            Some(err) => Err(err),
+            None => res.map(|evaluation| evaluation.value),
+        }
+    }
+
+    /// Compute the iterated integral of the function over the levels that follow the outer
+    /// variables, using the method of each level over its variable.
+    fn integrate_iterated(
+        &self,
+        levels: &[IteratedLevel<X>],
+        closure: &dyn Fn(&[X]) -> X,
+        method: ComputeMethod,
+        outer: &[X],
+    ) -> Result<X, IntegraalError> {
+        let level = &levels[outer.len()];
+        let Some(domain) = iterated::domain(level, outer, self.allow_descending)? else {
+            return Ok(X::zero());
+        };
+        let point = |x: X| {
+            let mut point = outer.to_vec();
+            point.push(x);
+            point
+        };
+        let level_method = level.method.as_ref().unwrap_or(&method);
+        if outer.len() + 1 == levels.len() {
+            let integrand = |x: X| closure(&point(x));
+            return self
+                .integrate_samples(&domain, Samples::Closure(&integrand), *level_method)
+                .map(|evaluation| evaluation.value);
+        }
+
+        // inner integrals cannot return errors through the outer closure; the first one is kept
+        let error = Cell::new(None);
+        let integrand = |x: X| {
+            self.integrate_iterated(levels, closure, method, &point(x))
+                .unwrap_or_else(|err| {
+                    let first = error.take().unwrap_or(err);
+                    error.set(Some(first));
+                    X::zero()
+                })
+        };
+        let res = self.integrate_samples(&domain, Samples::Closure(&integrand), *level_method);
+        match error.into_inner() {
+            Some(err) => Err(err),
+            None => res.map(|evaluation| evaluation.value),
+        }
+    }
+
+    /// Compute the integral of values over a regular grid, applying the method along each axis.
+    ///
+    /// The last axis is contracted first, reading values directly from the buffer; the other axes
+    /// are then contracted over the reduced buffer.
+    fn integrate_grid(
+        &self,
+        vals: &[X],
+        shape: &[usize],
+        spacing: &[X],
+        mask: Option<&[bool]>,
+        method: ComputeMethod,
+    ) -> Result<X, IntegraalError> {
+        let axis = |k: usize| DomainDescriptor::Uniform {
+            start: X::zero(),
+            step: spacing[k],
+            n_step: shape[k],
+        };
+        let last = shape.len() - 1;
+        let n_last = shape[last];
+        let mut reduced = vals
+            .chunks(n_last)
+            .enumerate()
+            .map(|(line, chunk)| {
+                let res = if let Some(mask) = mask {
+                    let masked: Vec<X> = chunk
+                        .iter()
+                        .zip(&mask[line * n_last..(line + 1) * n_last])
+                        .map(|(val, inside)| if *inside { *val } else { X::zero() })
+                        .collect();
+                    self.integrate_samples(&axis(last), Samples::Values(&masked, false), method)
+                } else {
+                    self.integrate_samples(&axis(last), Samples::Values(chunk, false), method)
+                };
+                // report the index of the sample in the whole buffer
+                res.map(|evaluation| evaluation.value)
+                    .map_err(|err| match err {
+                        IntegraalError::NonFinite { index, abscissa } => {
+                            IntegraalError::NonFinite {
+                                index: line * n_last + index,
+                                abscissa,
+                            }
+                        }
+                        err => err,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for k in (0..last).rev() {
+            reduced = reduced
+                .chunks(shape[k])
+                .map(|chunk| {
+                    self.integrate_samples(&axis(k), Samples::Values(chunk, false), method)
+                        .map(|evaluation| evaluation.value)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+        Ok(reduced[0])
+    }
+
+    /// Compute the integral; closure values over the domain are read from / written to `cache`
+    /// if it is specified.
+    fn integrate(
+        &self,
+        domain: &DomainDescriptor<X>,
+        function: &FunctionDescriptor<X>,
+        method: ComputeMethod,
+        cache: Option<&OnceCell<Vec<X>>>,
+    ) -> Result<Evaluation<X>, IntegraalError> {
+        if let FunctionDescriptor::MultiClosure(_) | FunctionDescriptor::VectorField(_) = function {
+            return Err(IntegraalError::InconsistentParameters(String::from(
+                "functions of several variables require a region (`RegionDescriptor`)",
+            )));
+        }
+        if let DomainDescriptor::Piecewise(pieces) = domain {
+            return self.integrate_pieces(pieces, function, method);
+        }
+
+        let samples = match (function, cache) {
+            (FunctionDescriptor::Values(vals), _) => Samples::Values(vals.as_slice(), true),
             (
-                Some(FunctionDescriptor::Values(vals)),
-                Some(DomainDescriptor::Uniform { .. })
-            ) => values_uniform_arm(vals, domain, method)?,
-            // function descriptor -- closure
-            // domain descriptor   -- explicit
+                FunctionDescriptor::Closure(_) | FunctionDescriptor::Oscillatory { .. },
+                Some(cache),
+            ) if method.uses_domain_nodes() => Samples::Values(
+                cache
+                    .get_or_init(|| {
+                        (0..domain.n_sample())
+                            .map(|idx| function.evaluate(domain.abscissa(idx)))
+                            .collect()
+                    })
+                    .as_slice(),
+                false,
+            ),
+            (FunctionDescriptor::Closure(closure), _) => Samples::Closure(closure.as_ref()),
+            (FunctionDescriptor::Oscillatory { .. }, _) => Samples::oscillatory(function),
+            // rejected above
+            (FunctionDescriptor::MultiClosure(_) | FunctionDescriptor::VectorField(_), _) => {
+                unreachable!()
+            }
+        };
+
+        self.integrate_samples(domain, samples, method)
+    }
+
+    /// Compute the integral over each piece of a piecewise domain, and sum the results.
+    fn integrate_pieces(
+        &self,
+        pieces: &[Piece<X>],
+        function: &FunctionDescriptor<X>,
+        method: ComputeMethod,
+    ) -> Result<Evaluation<X>, IntegraalError> {
+        validation::validate_pieces(pieces, method)?;
+        let n_sample: usize = pieces.iter().map(|piece| piece.domain.n_sample()).sum();
+        if let FunctionDescriptor::Values(vals) = function {
+            if vals.len() != n_sample {
+                return Err(IntegraalError::LengthMismatch {
+                    domain: n_sample,
+                    function: vals.len(),
+                });
+            }
+        }
+
+        let mut offset = 0;
+        let mut results = Vec::with_capacity(pieces.len());
+        let mut breakpoints = Vec::new();
+        for (piece_id, piece) in pieces.iter().enumerate() {
+            let method = piece.method.as_ref().unwrap_or(&method);
+            let n_sample = piece.domain.n_sample();
+            let res = match function {
+                FunctionDescriptor::Values(vals) => self.integrate_samples(
+                    &piece.domain,
+                    Samples::Values(&vals[offset..offset + n_sample], true),
+                    *method,
+                ),
+                FunctionDescriptor::Closure(closure) => {
+                    // evaluate the closure on the piece's side of breakpoints
+                    let (first, last) = (
+                        piece.domain.abscissa(0),
+                        piece.domain.abscissa(n_sample - 1),
+                    );
+                    let (after, before) = (piece_id > 0, piece_id + 1 < pieces.len());
+                    let one_sided = |x: X| {
+                        if after && x == first {
+                            closure(nudge(x, true))
+                        } else if before && x == last {
+                            closure(nudge(x, false))
+                        } else {
+                            closure(x)
+                        }
+                    };
+                    self.integrate_samples(&piece.domain, Samples::Closure(&one_sided), *method)
+                }
+                FunctionDescriptor::Oscillatory { .. } => {
+                    self.integrate_samples(&piece.domain, Samples::oscillatory(function), *method)
+                }
+                // rejected by the caller
+                FunctionDescriptor::MultiClosure(_) | FunctionDescriptor::VectorField(_) => {
+                    unreachable!()
+                }
+            };
+            // report the index of the sample in the whole domain
+            let res = res.map_err(|err| match err {
+                IntegraalError::NonFinite { index, abscissa } => IntegraalError::NonFinite {
+                    index: offset + index,
+                    abscissa,
+                },
+                err => err,
+            })?;
+            results.push(res.value);
+            breakpoints.extend(res.breakpoints);
+            offset += n_sample;
+        }
+
+        Ok(Evaluation {
+            value: results.into_iter().sum_with(self.summation),
+            breakpoints,
+            error: None,
+            evaluations: None,
+        })
+    }
+
+    /// Compute the integral over a single domain.
+    fn integrate_samples(
+        &self,
+        domain: &DomainDescriptor<X>,
+        samples: Samples<X>,
+        method: ComputeMethod,
+    ) -> Result<Evaluation<X>, IntegraalError> {
+        // integrate over the increasing domain, then flip the result if necessary
+        let descending = validation::validate(domain, method, self.allow_descending)?;
+        let reversed;
+        let increasing = if descending {
+            reversed = validation::reverse(domain);
+            &reversed
+        } else {
+            domain
+        };
+
+        let reversed_vals: Vec<X>;
+        let samples = match samples {
+            Samples::Values(vals, detect) if descending => {
+                reversed_vals = vals.iter().rev().copied().collect();
+                Samples::Values(&reversed_vals, detect)
+            }
+            samples => samples,
+        };
+
+        let res = match (samples, self.detection) {
+            (samples, _) if self.periodic => self.integrate_periodic(increasing, samples, method),
+            (Samples::Values(vals, true), Some(settings)) => {
+                self.split_values(vals, increasing, method, &settings)
+            }
+            (Samples::Values(vals, _), _) => {
+                values_arm(vals, increasing, method, self.non_finite, self.summation)
+                    .map(Evaluation::from)
+            }
+            (Samples::Closure(closure), _) => {
+                checked_closure_arm(closure, increasing, method, self.non_finite, self.summation)
+                    .map(Evaluation::from)
+            }
+            #[cfg(feature = "levin")]
             (
-                Some(FunctionDescriptor::Closure(closure)),
-                Some(DomainDescriptor::Explicit(args)),
-            ) => closure_explicit_arm(closure, args, method)?,
-            // function descriptor -- closure
-            // domain descriptor   -- uniform
+                Samples::Oscillatory {
+                    amplitude,
+                    phase,
+                    phase_derivative,
+                    kind,
+                },
+                _,
+            ) if matches!(method, ComputeMethod::Levin { .. }) => {
+                let ComputeMethod::Levin { n_nodes } = method else {
+                    unreachable!()
+                };
+                let limits = (
+                    increasing.abscissa(0),
+                    increasing.abscissa(increasing.n_sample() - 1),
+                );
+                oscillatory::levin(amplitude, phase, phase_derivative, kind, limits, n_nodes)
+                    .map(Evaluation::from)
+            }
             (
-                Some(FunctionDescriptor::Closure(closure)),
-                Some(DomainDescriptor::Uniform { .. }),
-            ) => closure_uniform_arm(closure, domain, method)?,
-            (_, _) => unreachable!(),
+                Samples::Oscillatory {
+                    amplitude,
+                    phase,
+                    kind,
+                    ..
+                },
+                _,
+            ) => {
+                let closure = |x: X| amplitude(x) * oscillatory::oscillator(kind, phase(x));
+                checked_closure_arm(
+                    &closure,
+                    increasing,
+                    method,
+                    self.non_finite,
+                    self.summation,
+                )
+                .map(Evaluation::from)
+            }
         };
 
-        self.function = None; // is this really useful? we could directly return if this wasn't here
-        Ok(res)
+        match res {
+            Ok(mut evaluation) if descending => {
+                evaluation.value = -evaluation.value;
+                Ok(evaluation)
+            }
+            // report the index of the sample in the domain as it was described
+            Err(IntegraalError::NonFinite { index, abscissa }) if descending => {
+                Err(IntegraalError::NonFinite {
+                    index: domain.n_sample() - 1 - index,
+                    abscissa,
+                })
+            }
+            res => res,
+        }
+    }
+
+    /// Split values at detected discontinuities, and integrate each piece separately.
+    fn split_values(
+        &self,
+        vals: &[X],
+        domain: &DomainDescriptor<X>,
+        method: ComputeMethod,
+        settings: &DiscontinuityDetection,
+    ) -> Result<Evaluation<X>, IntegraalError> {
+        let features = detection::detect(vals, domain, settings);
+        if features.is_empty() {
+            return values_arm(vals, domain, method, self.non_finite, self.summation)
+                .map(Evaluation::from);
+        }
+
+        // (first sample, last sample, contains a discontinuity)
+        let mut ranges = Vec::with_capacity(2 * features.len() + 1);
+        let mut start = 0;
+        for feature in &features {
+            ranges.push((start, feature.lo, false));
+            ranges.push((feature.lo, feature.hi, true));
+            start = feature.hi;
+        }
+        ranges.push((start, vals.len() - 1, false));
+
+        let mut results = Vec::with_capacity(ranges.len());
+        for (lo, hi, discontinuous) in ranges {
+            if lo == hi {
+                continue;
+            }
+            let sub_domain = domain.slice(lo, hi);
+            let method =
+                if discontinuous || validation::validate(&sub_domain, method, false).is_err() {
+                    &ComputeMethod::Trapezoid
+                } else {
+                    &method
+                };
+            let res = values_arm(
+                &vals[lo..=hi],
+                &sub_domain,
+                *method,
+                self.non_finite,
+                self.summation,
+            )
+            .map_err(|err| match err {
+                IntegraalError::NonFinite { index, abscissa } => IntegraalError::NonFinite {
+                    index: lo + index,
+                    abscissa,
+                },
+                err => err,
+            })?;
+            results.push(res);
+        }
+
+        Ok(Evaluation {
+            value: results.into_iter().sum_with(self.summation),
+            breakpoints: features
+                .iter()
+                .map(|feature| Breakpoint {
+                    kind: feature.kind,
+                    start: domain.abscissa(feature.lo),
+                    end: domain.abscissa(feature.hi),
+                })
+                .collect(),
+            error: None,
+            evaluations: None,
+        })
+    }
+
+    /// Compute the integral of a function that is periodic over the domain.
+    fn integrate_periodic(
+        &self,
+        domain: &DomainDescriptor<X>,
+        samples: Samples<X>,
+        method: ComputeMethod,
+    ) -> Result<Evaluation<X>, IntegraalError> {
+        validation::validate_periodic(domain, method)?;
+        let Some(grid) = UniformGrid::from_domain(domain) else {
+            unreachable!()
+        };
+
+        let evaluated: Vec<X>;
+        let vals = match samples {
+            Samples::Values(vals, _) => vals,
+            Samples::Closure(closure) => {
+                evaluated = (0..grid.n_sample())
+                    .map(|i| closure(grid.node(i)))
+                    .collect();
+                &evaluated
+            }
+            Samples::Oscillatory {
+                amplitude,
+                phase,
+                kind,
+                ..
+            } => {
+                evaluated = (0..grid.n_sample())
+                    .map(|i| {
+                        let x = grid.node(i);
+                        amplitude(x) * oscillatory::oscillator(kind, phase(x))
+                    })
+                    .collect();
+                &evaluated
+            }
+        };
+        let sanitized = match non_finite::sanitize(vals, domain, self.non_finite)? {
+            Some((_, Some(_))) => {
+                return Err(IntegraalError::Unimplemented {
+                    method,
+                    context: "periodic functions with skipped samples",
+                });
+            }
+            Some((vals, None)) => Some(vals),
+            None => None,
+        };
+        let vals = sanitized.as_deref().unwrap_or(vals);
+
+        // all samples have the same weight; the end point of the period is not sampled
+        let value = grid.step() * vals.iter().copied().sum_with(self.summation);
+        let error = vals.len().is_multiple_of(2).then(|| {
+            let coarse = grid.step()
+                * X::from(2.0).unwrap()
+                * vals.iter().step_by(2).copied().sum_with(self.summation);
+            (value - coarse).abs()
+        });
+        Ok(Evaluation {
+            value,
+            breakpoints: Vec::new(),
+            error,
+            evaluations: None,
+        })
     }
 }
 
 // --- internals
 
+/// Function samples over a single domain.
+///
+/// Values are flagged if they may be analyzed for discontinuities, i.e. if they were not
+/// computed from a closure.
+#[derive(Clone, Copy)]
+enum Samples<'a, X> {
+    Values(&'a [X], bool),
+    Closure(&'a dyn Fn(X) -> X),
+    Oscillatory {
+        amplitude: &'a dyn Fn(X) -> X,
+        phase: &'a dyn Fn(X) -> X,
+        #[cfg_attr(not(feature = "levin"), allow(dead_code))]
+        phase_derivative: &'a dyn Fn(X) -> X,
+        kind: Oscillator,
+    },
+}
+
+impl<'a, X> Samples<'a, X> {
+    fn oscillatory(function: &'a FunctionDescriptor<X>) -> Self
+    where
+        X: Scalar,
+    {
+        let FunctionDescriptor::Oscillatory {
+            amplitude,
+            phase,
+            phase_derivative,
+            kind,
+        } = function
+        else {
+            unreachable!()
+        };
+        Samples::Oscillatory {
+            amplitude: amplitude.as_ref(),
+            phase: phase.as_ref(),
+            phase_derivative: phase_derivative.as_ref(),
+            kind: *kind,
+        }
+    }
+}
+
+/// Move `x` by a few ULPs, towards positive infinity if `up` is set, negative infinity otherwise.
+fn nudge<X: Scalar>(x: X, up: bool) -> X {
+    let delta = (x.abs() * X::epsilon()).max(X::min_positive_value());
+    if up {
+        x + delta
+    } else {
+        x - delta
+    }
+}
+
+// function descriptor -- values
+fn values_arm<X: Scalar>(
+    vals: &[X],
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    let sanitized = non_finite::sanitize(vals, domain, policy)?;
+    // skipped samples may leave too few of them for the method
+    if let Some((vals, Some(_))) = &sanitized {
+        let required = validation::min_samples(method);
+        if vals.len() < required {
+            return Err(IntegraalError::TooFewSamples {
+                method,
+                required,
+                got: vals.len(),
+            });
+        }
+    }
+    match (sanitized, domain) {
+        (Some((vals, Some(args))), _) => {
+            values_explicit_arm(&vals, args.as_slice(), method, summation)
+        }
+        (Some((vals, None)), DomainDescriptor::Explicit(args)) => {
+            values_explicit_arm(&vals, args.as_slice(), method, summation)
+        }
+        (Some((vals, None)), DomainDescriptor::Uniform { .. }) => {
+            values_uniform_arm(&vals, domain, method, summation)
+        }
+        (None, DomainDescriptor::Explicit(args)) => {
+            values_explicit_arm(vals, args.as_slice(), method, summation)
+        }
+        (None, DomainDescriptor::Uniform { .. }) => {
+            values_uniform_arm(vals, domain, method, summation)
+        }
+        (Some((vals, None)), DomainDescriptor::Generated(generator)) => {
+            values_explicit_arm(&vals, generator, method, summation)
+        }
+        (None, DomainDescriptor::Generated(generator)) => {
+            values_explicit_arm(vals, generator, method, summation)
+        }
+        // pieces are integrated separately
+        (_, DomainDescriptor::Piecewise(_)) => unreachable!(),
+    }
+}
+
+// function descriptor -- closure, with non-finite value checks
+fn checked_closure_arm<X: Scalar>(
+    closure: &dyn Fn(X) -> X,
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    // falling back to values is only equivalent if the closure is sampled at the domain's points
+    if matches!(policy, NonFinitePolicy::Skip | NonFinitePolicy::Interpolate)
+        && !method.uses_domain_nodes()
+    {
+        return Err(IntegraalError::InconsistentParameters(format!(
+            "the {policy:?} non-finite policy requires a method that only evaluates closures at the domain's samples, which {} does not",
+            method.name()
+        )));
+    }
+    // non-finite values are replaced on the fly; we only record the first one
+    let faulty = Cell::new(None);
+    let checked = |x: X| {
+        let y = closure(x);
+        if y.is_finite() {
+            y
+        } else {
+            if faulty.get().is_none() {
+                faulty.set(Some(x));
+            }
+            X::zero()
+        }
+    };
+    // faulty values take precedence over errors they may cause, e.g. a lack of convergence
+    let res = closure_arm(checked, domain, method, summation);
+    match (faulty.get(), policy) {
+        (None, _) | (Some(_), NonFinitePolicy::ReplaceWithZero) => res,
+        (Some(x), NonFinitePolicy::Error) => Err(non_finite::error_at(domain, x)),
+        // the policy requires neighbouring samples, so we fall back to values
+        (Some(_), NonFinitePolicy::Skip | NonFinitePolicy::Interpolate) => {
+            let vals: Vec<X> = (0..domain.n_sample())
+                .map(|idx| closure(domain.abscissa(idx)))
+                .collect();
+            values_arm(&vals, domain, method, policy, summation)
+        }
+    }
+}
+
+// function descriptor -- closure
+fn closure_arm<X: Scalar>(
+    closure: impl Fn(X) -> X,
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    #[cfg(feature = "singular")]
+    {
+        let limits = (domain.abscissa(0), domain.abscissa(domain.n_sample() - 1));
+        match method {
+            ComputeMethod::CauchyPrincipalValue { pole, n_nodes } => {
+                let pole = X::from(pole).unwrap();
+                return Ok(singular::cauchy(closure, limits, pole, n_nodes, summation));
+            }
+            ComputeMethod::AlgebraicLogarithmic {
+                alpha,
+                beta,
+                log,
+                n_levels,
+            } => {
+                let exponents = (X::from(alpha).unwrap(), X::from(beta).unwrap());
+                return Ok(singular::algebraic_logarithmic(
+                    closure, limits, exponents, log, n_levels, summation,
+                ));
+            }
+            _ => {}
+        }
+    }
+    match domain {
+        DomainDescriptor::Explicit(args) => {
+            closure_explicit_arm(closure, args.as_slice(), method, summation)
+        }
+        DomainDescriptor::Generated(generator) => {
+            closure_explicit_arm(closure, generator, method, summation)
+        }
+        // pieces are integrated separately
+        DomainDescriptor::Piecewise(_) => unreachable!(),
+        DomainDescriptor::Uniform { .. } => closure_uniform_arm(closure, domain, method, summation),
+    }
+}
+
 // function descriptor -- values
 // domain descriptor   -- explicit
+#[allow(clippy::too_many_lines)]
 fn values_explicit_arm<X: Scalar>(
     vals: &[X],
-    args: &[X],
-    method: &ComputeMethod,
+    args: &(impl Nodes<X> + ?Sized),
+    method: ComputeMethod,
+    summation: Summation,
 ) -> Result<X, IntegraalError> {
-    if args.len() != vals.len() {
-        return Err(IntegraalError::InconsistentParameters(
-            "function and domain value slices have different lengthes",
-        ));
+    if args.n_nodes() != vals.len() {
+        return Err(IntegraalError::LengthMismatch {
+            domain: args.n_nodes(),
+            function: vals.len(),
+        });
     }
-    let n_sample = args.len();
+    let n_sample = args.n_nodes();
 
     // because the domain may be not uniform, we have to compute step values
     let res = match method {
         ComputeMethod::RectangleLeft => (1..n_sample)
             .map(|idx| {
-                let step = args[idx] - args[idx - 1];
+                let step = args.node(idx) - args.node(idx - 1);
                 vals[idx - 1] * step
             })
-            .sum(),
+            .sum_with(summation),
         ComputeMethod::RectangleRight => (1..n_sample)
             .map(|idx| {
-                let step = args[idx] - args[idx - 1];
+                let step = args.node(idx) - args.node(idx - 1);
                 vals[idx] * step
             })
-            .sum(),
+            .sum_with(summation),
         ComputeMethod::Trapezoid => (1..n_sample)
             .map(|idx| {
-                let step = args[idx] - args[idx - 1];
+                let step = args.node(idx) - args.node(idx - 1);
                 let y1 = vals[idx - 1];
                 let y2 = vals[idx];
                 (y1.min(y2) + num_traits::abs(y1 - y2) / X::from_f32(2.0).unwrap()) * step
             })
-            .sum(),
+            .sum_with(summation),
         ComputeMethod::Simpson => {
             // using the formula for irregularly spaced data:
             // https://en.wikipedia.org/wiki/Simpson%27s_rule#Composite_Simpson's_rule_for_irregularly_spaced_data
-            // the formula is a sum from 0 to N-2, N the number of subintervals; so N = n_sample-1
-            let indices: Vec<_> = (0..n_sample - 4).collect();
-            indices
-                .windows(3)
-                .map(|is| {
-                    let [i, ip1, ip2] = is else {
-                        unreachable!();
-                    };
-                    let (h_i, h_ip1) = (args[*ip1] - args[*i], args[*ip2] - args[*ip1]);
-                    let c_i = X::from(2.0).unwrap() - h_ip1 / h_i;
+            // the formula is a sum over pairs of subintervals, with a correction term applied to
+            // the last subinterval if their number is odd; N = n_sample-1
+            let n_seg = n_sample - 1;
+            let (two, three, six) = (
+                X::from(2.0).unwrap(),
+                X::from(3.0).unwrap(),
+                X::from(6.0).unwrap(),
+            );
+            let pairs: X = (0..n_seg - 1)
+                .step_by(2)
+                .map(|i| {
+                    let (h_i, h_ip1) = (
+                        args.node(i + 1) - args.node(i),
+                        args.node(i + 2) - args.node(i + 1),
+                    );
+                    let c_i = two - h_ip1 / h_i;
                     let c_ip1 = (h_i + h_ip1).powi(2) / (h_i * h_ip1);
-                    let c_ip2 = X::from(2.0).unwrap() - h_i / h_ip1;
-                    (h_i + h_ip1) / X::from(6.0).unwrap()
-                        * (c_i * vals[*i] + c_ip1 * vals[*ip1] + c_ip2 * vals[*ip2])
+                    let c_ip2 = two - h_i / h_ip1;
+                    (h_i + h_ip1) / six
+                        * (c_i * vals[i] + c_ip1 * vals[i + 1] + c_ip2 * vals[i + 2])
                 })
-                .sum()
+                .sum_with(summation);
+            let correction = if n_seg % 2 == 1 {
+                let (h_a, h_b) = (
+                    args.node(n_seg - 1) - args.node(n_seg - 2),
+                    args.node(n_seg) - args.node(n_seg - 1),
+                );
+                let alpha = (two * h_b.powi(2) + three * h_b * h_a) / (six * (h_a + h_b));
+                let beta = (h_b.powi(2) + three * h_b * h_a) / (six * h_a);
+                let eta = h_b.powi(3) / (six * h_a * (h_a + h_b));
+                alpha * vals[n_seg] + beta * vals[n_seg - 1] - eta * vals[n_seg - 2]
+            } else {
+                X::zero()
+            };
+            pairs + correction
         }
         #[cfg(feature = "boole")]
         ComputeMethod::Boole { .. } => {
-            return Err(IntegraalError::Unimplemented(
-                "Boole's method isn't implemented for non-uniform domains",
-            ));
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
         }
         #[cfg(feature = "romberg")]
         ComputeMethod::Romberg { .. } => {
-            return Err(IntegraalError::Unimplemented(
-                "Romberg's method isn't implemented for non-uniform domains",
-            ));
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
+        }
+        #[cfg(feature = "filon")]
+        ComputeMethod::Filon { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
+        }
+        #[cfg(feature = "gregory")]
+        ComputeMethod::Gregory { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
+        }
+        #[cfg(feature = "richardson")]
+        ComputeMethod::Richardson { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            });
+        }
+        #[cfg(feature = "open")]
+        ComputeMethod::Midpoint | ComputeMethod::OpenNewtonCotes { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            });
+        }
+        // rejected during validation
+        #[cfg(feature = "simplex")]
+        ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. } => unreachable!(),
+        #[cfg(feature = "polar")]
+        ComputeMethod::Lebedev { .. }
+        | ComputeMethod::SphericalProduct { .. }
+        | ComputeMethod::PolarGauss { .. } => unreachable!(),
+        #[cfg(feature = "sparse")]
+        ComputeMethod::Smolyak { .. } => unreachable!(),
+        #[cfg(feature = "adaptive")]
+        ComputeMethod::GenzMalik { .. } => unreachable!(),
+        #[cfg(feature = "levin")]
+        ComputeMethod::Levin { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-oscillatory function descriptors",
+            });
+        }
+        #[cfg(feature = "singular")]
+        ComputeMethod::CauchyPrincipalValue { .. } | ComputeMethod::AlgebraicLogarithmic { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            });
         }
         #[cfg(feature = "montecarlo")]
         ComputeMethod::MonteCarlo { n_sample } => {
@@ -169,27 +1301,29 @@ fn values_explicit_arm<X: Scalar>(
                 max = max.max(*v);
             }
             let height: X = max - min;
-            let widths = args.windows(2).enumerate().map(|(i, slice)| {
-                let [a, b] = slice else { unreachable!() };
-                (vals[i].min(X::zero())..vals[i].max(X::zero()), (*b - *a))
+            let widths = (1..args.n_nodes()).map(|i| {
+                (
+                    vals[i - 1].min(X::zero())..vals[i - 1].max(X::zero()),
+                    args.node(i) - args.node(i - 1),
+                )
             });
-            let mut rng = rand::thread_rng();
+            let mut rng = rand::rng();
             let random_numbers: Vec<X> = (&mut rng)
                 .sample_iter(
                     rand::distr::Uniform::new(min.to_f64().unwrap(), max.to_f64().unwrap())
                         .unwrap(),
                 )
-                .take(*n_sample * (args.len() - 1))
+                .take(n_sample * (args.n_nodes() - 1))
                 .map(|s| X::from(s).unwrap())
                 .collect();
             let total_in: X = random_numbers
-                .chunks_exact(*n_sample)
+                .chunks_exact(n_sample)
                 .zip(widths)
                 .map(|(samples, (range, width))| {
                     X::from(samples.iter().filter(|s| range.contains(s)).count()).unwrap() * width
                 })
-                .sum();
-            height * total_in / X::from(*n_sample * (args.len() - 1)).unwrap()
+                .sum_with(summation);
+            height * total_in / X::from(n_sample * (args.n_nodes() - 1)).unwrap()
         }
     };
 
@@ -198,68 +1332,78 @@ fn values_explicit_arm<X: Scalar>(
 
 // function descriptor -- values
 // domain descriptor   -- uniform
-#[allow(clippy::cast_possible_truncation, clippy::too_many_lines)]
+#[allow(clippy::too_many_lines)]
 fn values_uniform_arm<X: Scalar>(
     vals: &[X],
     domain: &DomainDescriptor<X>,
-    method: &ComputeMethod,
+    method: ComputeMethod,
+    summation: Summation,
 ) -> Result<X, IntegraalError> {
-    let DomainDescriptor::Uniform {
-        start,
-        step,
-        n_step,
-    } = domain
-    else {
+    let Some(grid) = UniformGrid::from_domain(domain) else {
         unreachable!()
     };
+    let (step, n_step) = (grid.step(), grid.n_sample());
 
-    if *n_step != vals.len() {
-        return Err(IntegraalError::InconsistentParameters(
-            "provided function and domain value slices have different lengthes",
-        ));
+    if n_step != vals.len() {
+        return Err(IntegraalError::LengthMismatch {
+            domain: n_step,
+            function: vals.len(),
+        });
     }
 
     // we can use the uniform domain's step & number of step to compute areas
     let res = match method {
         ComputeMethod::RectangleLeft => {
             // ignore the last value since its a left rule
-            (0..*n_step - 1).map(|step_id| vals[step_id] * *step).sum()
+            (0..n_step - 1)
+                .map(|step_id| vals[step_id] * step)
+                .sum_with(summation)
         }
         ComputeMethod::RectangleRight => {
             // ignore the last value since its a left rule
-            (1..*n_step).map(|step_id| vals[step_id] * *step).sum()
+            (1..n_step)
+                .map(|step_id| vals[step_id] * step)
+                .sum_with(summation)
         }
-        ComputeMethod::Trapezoid => (1..*n_step)
+        ComputeMethod::Trapezoid => (1..n_step)
             .map(|step_id| {
                 let y1 = vals[step_id - 1];
                 let y2 = vals[step_id];
-                (y1.min(y2) + (y1 - y2).abs() / X::from_f32(2.0).unwrap()) * *step
+                (y1.min(y2) + (y1 - y2).abs() / X::from_f32(2.0).unwrap()) * step
             })
-            .sum(),
+            .sum_with(summation),
         ComputeMethod::Simpson => {
-            let indices: Vec<_> = (0..*n_step - 4).collect();
-            (*step / X::from(3.0).unwrap())
-                * indices
-                    .windows(3)
-                    .map(|is| {
-                        let [i, ip1, ip2] = is else {
-                            unreachable!();
-                        };
-                        vals[*i] + X::from(4.0).unwrap() * vals[*ip1] + vals[*ip2]
-                    })
-                    .sum()
+            // composite 1/3 rule over pairs of segments; if the number of segments is odd, the
+            // last three segments are integrated using the 3/8 rule
+            let n_seg = n_step - 1;
+            let n_pairs = if n_seg % 2 == 0 { n_seg } else { n_seg - 3 };
+            let (three, four) = (X::from(3.0).unwrap(), X::from(4.0).unwrap());
+            let pairs = (step / three)
+                * (0..n_pairs)
+                    .step_by(2)
+                    .map(|i| vals[i] + four * vals[i + 1] + vals[i + 2])
+                    .sum_with(summation);
+            let triple = if n_seg % 2 == 1 {
+                (three * step / X::from(8.0).unwrap())
+                    * (vals[n_seg - 3]
+                        + three * vals[n_seg - 2]
+                        + three * vals[n_seg - 1]
+                        + vals[n_seg])
+            } else {
+                X::zero()
+            };
+            pairs + triple
         }
         #[cfg(feature = "boole")]
         ComputeMethod::Boole { force } => {
-            let n_step = if *force {
-                *n_step - *n_step % 4
+            // the number of segments has been checked during validation
+            let n_step = if force {
+                n_step - (n_step - 1) % 4
             } else {
-                return Err(IntegraalError::BadParameters(
-                    "domain should be divided into a multiple of 4 segments for Boole's method",
-                ));
+                n_step
             };
 
-            let c = X::from(2.0 / 45.0).unwrap() * *step;
+            let c = X::from(2.0 / 45.0).unwrap() * step;
 
             let m1 = X::from(7.0).unwrap() * (vals[0] + vals[n_step - 1]);
 
@@ -273,42 +1417,81 @@ fn values_uniform_arm<X: Scalar>(
                     1 | 3 => c3 * vals[id], // odd
                     _ => unreachable!(),
                 })
-                .sum();
+                .sum_with(summation);
             c * (m1 + m2)
         }
         #[cfg(feature = "romberg")]
         ComputeMethod::Romberg { max_steps } => {
-            let (mut r1, mut r2) = (vec![X::zero(); *max_steps], vec![X::zero(); *max_steps]);
-            let end = *start + X::from(*n_step).unwrap() * *step;
+            // the finest level uses all samples, and each coarser level uses every other sample of
+            // the next one; the number of levels is limited by the number of times the segments
+            // can be halved
+            let n_seg = grid.n_segment();
+            let n_levels = max_steps.min(n_seg.trailing_zeros() as usize + 1);
+            let coarsest = 1 << (n_levels - 1);
             let half = X::from(0.5).unwrap();
-            let mut h = end - *start;
-
-            r1[0] = half * h * (vals[0] + vals[*n_step - 1]);
+            let mut h = step * X::from(coarsest).unwrap();
 
-            // since we dont take desired accuracy as an arg, we don't need early returns
-            // => the loop is written with an iterator instead of a regular for
-            (1..*max_steps).for_each(|i| {
-                let (rp, rc) = if i % 2 == 0 {
-                    (&mut r2, &mut r1)
-                } else {
-                    (&mut r1, &mut r2)
-                };
+            let inner = (coarsest..n_seg)
+                .step_by(coarsest)
+                .map(|i| vals[i])
+                .sum_with(summation);
+            let mut row = vec![half * h * (vals[0] + vals[n_seg]) + h * inner];
 
+            for level in 1..n_levels {
+                let stride = coarsest >> level;
                 h *= half;
-                let ep = 2_usize.pow(i as u32 - 1);
-                let c = (1..=ep).map(|j| vals[2 * j - 1]).sum::<X>();
-                rc[0] = h * c + half * rp[0];
-
-                (1..=i).for_each(|j| {
-                    let n_k = X::from(4_u32.pow(j as u32)).unwrap();
-                    rc[j] = (n_k * rc[j - 1] - rp[j - 1]) / (n_k - X::one());
-                });
-            });
-            if *n_step % 2 == 0 {
-                r1[*max_steps - 1]
-            } else {
-                r2[*max_steps - 1]
+                let c = (stride..n_seg)
+                    .step_by(2 * stride)
+                    .map(|i| vals[i])
+                    .sum_with(summation);
+                row = richardson_row(&row, half * row[0] + h * c, 2, 2);
             }
+            row[n_levels - 1]
+        }
+        #[cfg(feature = "filon")]
+        ComputeMethod::Filon { omega, kind } => {
+            oscillatory::filon(vals, &grid, X::from(omega).unwrap(), kind, summation)
+        }
+        #[cfg(feature = "gregory")]
+        ComputeMethod::Gregory { order } => gregory(vals, step, order, summation),
+        #[cfg(feature = "richardson")]
+        ComputeMethod::Richardson { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            });
+        }
+        #[cfg(feature = "open")]
+        ComputeMethod::Midpoint | ComputeMethod::OpenNewtonCotes { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            });
+        }
+        // rejected during validation
+        #[cfg(feature = "simplex")]
+        ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. } => unreachable!(),
+        #[cfg(feature = "polar")]
+        ComputeMethod::Lebedev { .. }
+        | ComputeMethod::SphericalProduct { .. }
+        | ComputeMethod::PolarGauss { .. } => unreachable!(),
+        #[cfg(feature = "sparse")]
+        ComputeMethod::Smolyak { .. } => unreachable!(),
+        #[cfg(feature = "adaptive")]
+        ComputeMethod::GenzMalik { .. } => unreachable!(),
+        #[cfg(feature = "levin")]
+        ComputeMethod::Levin { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-oscillatory function descriptors",
+            });
+        }
+        #[cfg(feature = "singular")]
+        ComputeMethod::CauchyPrincipalValue { .. } | ComputeMethod::AlgebraicLogarithmic { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            });
         }
         #[cfg(feature = "montecarlo")]
         ComputeMethod::MonteCarlo { n_sample } => {
@@ -318,22 +1501,22 @@ fn values_uniform_arm<X: Scalar>(
                 max = max.max(*v);
             }
             let intervals = vals.iter().map(|v| v.min(X::zero())..v.max(X::zero()));
-            let volume: X = (max - min) * (X::from(*n_step).unwrap() * *step);
-            let mut rng = rand::thread_rng();
+            let volume: X = (max - min) * grid.length();
+            let mut rng = rand::rng();
             let random_numbers: Vec<X> = (&mut rng)
                 .sample_iter(
                     rand::distr::Uniform::new(min.to_f64().unwrap(), max.to_f64().unwrap())
                         .unwrap(),
                 )
-                .take(*n_sample * *n_step)
+                .take(n_sample * n_step)
                 .map(|s| X::from(s).unwrap())
                 .collect();
             let total_in: usize = random_numbers
-                .chunks_exact(*n_sample)
+                .chunks_exact(n_sample)
                 .zip(intervals)
                 .map(|(samples, range)| samples.iter().filter(|s| range.contains(s)).count())
                 .sum();
-            volume * X::from(total_in as f64 / (*n_sample * *n_step) as f64).unwrap()
+            volume * X::from(total_in as f64 / (n_sample * n_step) as f64).unwrap()
         }
     };
 
@@ -342,96 +1525,175 @@ fn values_uniform_arm<X: Scalar>(
 
 // function descriptor -- closure
 // domain descriptor   -- explicit
-#[allow(clippy::unnecessary_wraps)]
+#[allow(clippy::unnecessary_wraps, clippy::too_many_lines)]
 fn closure_explicit_arm<X: Scalar>(
     closure: impl Fn(X) -> X,
-    args: &[X],
-    method: &ComputeMethod,
+    args: &(impl Nodes<X> + ?Sized),
+    method: ComputeMethod,
+    summation: Summation,
 ) -> Result<X, IntegraalError> {
     let res = match method {
-        ComputeMethod::RectangleLeft => (1..args.len())
+        ComputeMethod::RectangleLeft => (1..args.n_nodes())
             .map(|idx| {
-                let step = args[idx] - args[idx - 1];
-                closure(args[idx - 1]) * step
+                let step = args.node(idx) - args.node(idx - 1);
+                closure(args.node(idx - 1)) * step
             })
-            .sum(),
-        ComputeMethod::RectangleRight => (1..args.len())
+            .sum_with(summation),
+        ComputeMethod::RectangleRight => (1..args.n_nodes())
             .map(|idx| {
-                let step = args[idx] - args[idx - 1];
-                closure(args[idx]) * step
+                let step = args.node(idx) - args.node(idx - 1);
+                closure(args.node(idx)) * step
             })
-            .sum(),
-        ComputeMethod::Trapezoid => (1..args.len())
+            .sum_with(summation),
+        ComputeMethod::Trapezoid => (1..args.n_nodes())
             .map(|idx| {
-                let step = args[idx] - args[idx - 1];
-                let y1 = closure(args[idx - 1]);
-                let y2 = closure(args[idx]);
+                let step = args.node(idx) - args.node(idx - 1);
+                let y1 = closure(args.node(idx - 1));
+                let y2 = closure(args.node(idx));
                 (y1.min(y2) + (y1 - y2).abs() / X::from_f32(2.0).unwrap()) * step
             })
-            .sum(),
+            .sum_with(summation),
         ComputeMethod::Simpson => {
-            let indices: Vec<_> = (0..args.len() - 4).collect();
-            indices
-                .windows(3)
-                .map(|is| {
-                    let [i, ip1, ip2] = is else {
-                        unreachable!();
-                    };
-                    let (h_i, h_ip1) = (args[*ip1] - args[*i], args[*ip2] - args[*ip1]);
-                    let c_i = X::from(2.0).unwrap() - h_ip1 / h_i;
+            // same formula as for values; we evaluate the closure once per sample
+            let n_seg = args.n_nodes() - 1;
+            let (two, three, six) = (
+                X::from(2.0).unwrap(),
+                X::from(3.0).unwrap(),
+                X::from(6.0).unwrap(),
+            );
+            let pairs: X = (0..n_seg - 1)
+                .step_by(2)
+                .map(|i| {
+                    let (h_i, h_ip1) = (
+                        args.node(i + 1) - args.node(i),
+                        args.node(i + 2) - args.node(i + 1),
+                    );
+                    let c_i = two - h_ip1 / h_i;
                     let c_ip1 = (h_i + h_ip1).powi(2) / (h_i * h_ip1);
-                    let c_ip2 = X::from(2.0).unwrap() - h_i / h_ip1;
-                    (h_i + h_ip1) / X::from(6.0).unwrap()
-                        * (c_i * closure(args[*i])
-                            + c_ip1 * closure(args[*ip1])
-                            + c_ip2 * closure(args[*ip2]))
+                    let c_ip2 = two - h_i / h_ip1;
+                    (h_i + h_ip1) / six
+                        * (c_i * closure(args.node(i))
+                            + c_ip1 * closure(args.node(i + 1))
+                            + c_ip2 * closure(args.node(i + 2)))
                 })
-                .sum()
+                .sum_with(summation);
+            let correction = if n_seg % 2 == 1 {
+                let (h_a, h_b) = (
+                    args.node(n_seg - 1) - args.node(n_seg - 2),
+                    args.node(n_seg) - args.node(n_seg - 1),
+                );
+                let alpha = (two * h_b.powi(2) + three * h_b * h_a) / (six * (h_a + h_b));
+                let beta = (h_b.powi(2) + three * h_b * h_a) / (six * h_a);
+                let eta = h_b.powi(3) / (six * h_a * (h_a + h_b));
+                alpha * closure(args.node(n_seg)) + beta * closure(args.node(n_seg - 1))
+                    - eta * closure(args.node(n_seg - 2))
+            } else {
+                X::zero()
+            };
+            pairs + correction
         }
         #[cfg(feature = "boole")]
         ComputeMethod::Boole { .. } => {
-            return Err(IntegraalError::Unimplemented(
-                "Boole's method isn't implemented for non-uniform domains",
-            ));
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
         }
         #[cfg(feature = "romberg")]
         ComputeMethod::Romberg { .. } => {
-            return Err(IntegraalError::Unimplemented(
-                "Romberg's method isn't implemented for non-uniform domains",
-            ));
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
+        }
+        #[cfg(feature = "filon")]
+        ComputeMethod::Filon { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
+        }
+        #[cfg(feature = "gregory")]
+        ComputeMethod::Gregory { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
+        }
+        #[cfg(feature = "richardson")]
+        ComputeMethod::Richardson { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-uniform domains",
+            });
+        }
+        #[cfg(feature = "open")]
+        ComputeMethod::Midpoint => {
+            open_rule(closure, |i| args.node(i), args.n_nodes(), 1, summation)
+        }
+        #[cfg(feature = "open")]
+        ComputeMethod::OpenNewtonCotes { n_points } => open_rule(
+            closure,
+            |i| args.node(i),
+            args.n_nodes(),
+            n_points,
+            summation,
+        ),
+        // rejected during validation
+        #[cfg(feature = "simplex")]
+        ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. } => unreachable!(),
+        #[cfg(feature = "polar")]
+        ComputeMethod::Lebedev { .. }
+        | ComputeMethod::SphericalProduct { .. }
+        | ComputeMethod::PolarGauss { .. } => unreachable!(),
+        #[cfg(feature = "sparse")]
+        ComputeMethod::Smolyak { .. } => unreachable!(),
+        #[cfg(feature = "adaptive")]
+        ComputeMethod::GenzMalik { .. } => unreachable!(),
+        #[cfg(feature = "levin")]
+        ComputeMethod::Levin { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-oscillatory function descriptors",
+            });
+        }
+        // only the limits of the domain are used; see `closure_arm`
+        #[cfg(feature = "singular")]
+        ComputeMethod::CauchyPrincipalValue { .. } | ComputeMethod::AlgebraicLogarithmic { .. } => {
+            unreachable!()
         }
         #[cfg(feature = "montecarlo")]
         ComputeMethod::MonteCarlo { n_sample } => {
-            let (mut min, mut max) = (closure(args[0]), closure(args[0]));
-            for a in args {
-                min = min.min(closure(*a));
-                max = max.max(closure(*a));
+            let (mut min, mut max) = (closure(args.node(0)), closure(args.node(0)));
+            for i in 0..args.n_nodes() {
+                min = min.min(closure(args.node(i)));
+                max = max.max(closure(args.node(i)));
             }
             let height: X = max - min;
-            let widths = args.windows(2).map(|slice| {
-                let [a, b] = slice else { unreachable!() };
+            let widths = (1..args.n_nodes()).map(|i| {
+                let (a, b) = (args.node(i - 1), args.node(i));
                 (
-                    closure(*a).min(X::zero())..closure(*a).max(X::zero()),
-                    (*b - *a),
+                    closure(a).min(X::zero())..closure(a).max(X::zero()),
+                    (b - a),
                 )
             });
-            let mut rng = rand::thread_rng();
+            let mut rng = rand::rng();
             let random_numbers: Vec<X> = (&mut rng)
                 .sample_iter(
                     rand::distr::Uniform::new(min.to_f64().unwrap(), max.to_f64().unwrap())
                         .unwrap(),
                 )
-                .take(*n_sample * (args.len() - 1))
+                .take(n_sample * (args.n_nodes() - 1))
                 .map(|s| X::from(s).unwrap())
                 .collect();
             let total_in: X = random_numbers
-                .chunks_exact(*n_sample)
+                .chunks_exact(n_sample)
                 .zip(widths)
                 .map(|(samples, (range, width))| {
                     X::from(samples.iter().filter(|s| range.contains(s)).count()).unwrap() * width
                 })
-                .sum();
-            height * total_in / X::from(*n_sample * (args.len() - 1)).unwrap()
+                .sum_with(summation);
+            height * total_in / X::from(n_sample * (args.n_nodes() - 1)).unwrap()
         }
     };
     Ok(res)
@@ -439,160 +1701,363 @@ fn closure_explicit_arm<X: Scalar>(
 
 // function descriptor -- closure
 // domain descriptor   -- uniform
-#[allow(
-    clippy::unnecessary_wraps,
-    clippy::cast_possible_truncation,
-    clippy::too_many_lines
-)]
+#[allow(clippy::unnecessary_wraps, clippy::too_many_lines)]
 fn closure_uniform_arm<X: Scalar>(
     closure: impl Fn(X) -> X,
     domain: &DomainDescriptor<X>,
-    method: &ComputeMethod,
+    method: ComputeMethod,
+    summation: Summation,
 ) -> Result<X, IntegraalError> {
-    let DomainDescriptor::Uniform {
-        start,
-        step,
-        n_step,
-    } = domain
-    else {
+    let Some(grid) = UniformGrid::from_domain(domain) else {
         unreachable!()
     };
+    let (step, n_step) = (grid.step(), grid.n_sample());
 
     // compute args
     let res = match method {
-        ComputeMethod::RectangleLeft => (0..*n_step - 1)
+        ComputeMethod::RectangleLeft => (0..n_step - 1)
+            .map(|step_id| closure(grid.node(step_id)) * step)
+            .sum_with(summation),
+        ComputeMethod::RectangleRight => (1..n_step)
+            .map(|step_id| closure(grid.node(step_id)) * step)
+            .sum_with(summation),
+        ComputeMethod::Trapezoid => (1..n_step)
             .map(|step_id| {
-                let x = *start + *step * X::from_usize(step_id).unwrap();
-                closure(x) * *step
-            })
-            .sum(),
-        ComputeMethod::RectangleRight => (1..*n_step)
-            .map(|step_id| {
-                let x = *start + *step * X::from_usize(step_id).unwrap();
-                closure(x) * *step
-            })
-            .sum(),
-        ComputeMethod::Trapezoid => (1..*n_step)
-            .map(|step_id| {
-                let x1 = *start + *step * X::from_usize(step_id - 1).unwrap();
-                let x2 = *start + *step * X::from_usize(step_id).unwrap();
+                let x1 = grid.node(step_id - 1);
+                let x2 = grid.node(step_id);
                 let y1 = closure(x1);
                 let y2 = closure(x2);
-                (y1.min(y2) + (y1 - y2).abs() / X::from_f32(2.0).unwrap()) * *step
+                (y1.min(y2) + (y1 - y2).abs() / X::from_f32(2.0).unwrap()) * step
             })
-            .sum(),
+            .sum_with(summation),
         ComputeMethod::Simpson => {
-            let indices: Vec<_> = (0..*n_step - 4).collect();
-            (*step / X::from(3.0).unwrap())
-                * indices
-                    .windows(3)
-                    .map(|is| {
-                        let [i, ip1, ip2] = is else {
-                            unreachable!();
-                        };
-                        closure(*start + *step * X::from(*i).unwrap())
-                            + X::from(4.0).unwrap()
-                                * closure(*start + *step * X::from(*ip1).unwrap())
-                            + closure(*start + *step * X::from(*ip2).unwrap())
-                    })
-                    .sum()
+            // composite 1/3 rule over pairs of segments; if the number of segments is odd, the
+            // last three segments are integrated using the 3/8 rule
+            let n_seg = n_step - 1;
+            let n_pairs = if n_seg % 2 == 0 { n_seg } else { n_seg - 3 };
+            let (three, four) = (X::from(3.0).unwrap(), X::from(4.0).unwrap());
+            let x = |i: usize| grid.node(i);
+            let pairs = (step / three)
+                * (0..n_pairs)
+                    .step_by(2)
+                    .map(|i| closure(x(i)) + four * closure(x(i + 1)) + closure(x(i + 2)))
+                    .sum_with(summation);
+            let triple = if n_seg % 2 == 1 {
+                (three * step / X::from(8.0).unwrap())
+                    * (closure(x(n_seg - 3))
+                        + three * closure(x(n_seg - 2))
+                        + three * closure(x(n_seg - 1))
+                        + closure(x(n_seg)))
+            } else {
+                X::zero()
+            };
+            pairs + triple
         }
         #[cfg(feature = "boole")]
         ComputeMethod::Boole { force } => {
-            let n_step = if *force {
-                *n_step - *n_step % 4
+            // the number of segments has been checked during validation
+            let n_step = if force {
+                n_step - (n_step - 1) % 4
             } else {
-                return Err(IntegraalError::BadParameters(
-                    "domain should be divided into a multiple of 4 segments for Boole's method",
-                ));
+                n_step
             };
 
-            let c = X::from(2.0 / 45.0).unwrap() * *step;
+            let c = X::from(2.0 / 45.0).unwrap() * step;
 
-            let m1 = X::from(7.0).unwrap()
-                * (closure(*start) + closure(*start + X::from(n_step - 1).unwrap() * *step));
+            let m1 =
+                X::from(7.0).unwrap() * (closure(grid.node(0)) + closure(grid.node(n_step - 1)));
 
             let c1 = X::from(14.0).unwrap();
             let c2 = X::from(12.0).unwrap();
             let c3 = X::from(32.0).unwrap();
             let m2: X = (1..n_step - 1)
                 .map(|id| match id % 4 {
-                    0 => c1 * closure(*start + X::from(id).unwrap() * *step), // multiple of 4
-                    2 => c2 * closure(*start + X::from(id).unwrap() * *step), // pair, non-multiple of 4
-                    1 | 3 => c3 * closure(*start + X::from(id).unwrap() * *step), // odd
+                    0 => c1 * closure(grid.node(id)),     // multiple of 4
+                    2 => c2 * closure(grid.node(id)),     // pair, non-multiple of 4
+                    1 | 3 => c3 * closure(grid.node(id)), // odd
                     _ => unreachable!(),
                 })
-                .sum();
+                .sum_with(summation);
             c * (m1 + m2)
         }
         #[cfg(feature = "romberg")]
         ComputeMethod::Romberg { max_steps } => {
-            let (mut r1, mut r2) = (vec![X::zero(); *max_steps], vec![X::zero(); *max_steps]);
-            let end = *start + X::from(*n_step).unwrap() * *step;
+            // the closure is evaluated on successive dyadic subdivisions of the interval
             let half = X::from(0.5).unwrap();
-            let mut h = end - *start;
+            let mut h = grid.length();
 
-            r1[0] = half
-                * h
-                * (closure(*start) + closure(*start + X::from(*n_step - 1).unwrap() * *step));
-
-            // since we dont take desired accuracy as an arg, we don't need early returns
-            // => the loop is written with an iterator instead of a regular for
-            (1..*max_steps).for_each(|i| {
-                let (rp, rc) = if i % 2 == 0 {
-                    (&mut r2, &mut r1)
-                } else {
-                    (&mut r1, &mut r2)
-                };
+            let mut row = vec![half * h * (closure(grid.start()) + closure(grid.end()))];
 
+            for level in 1..max_steps {
                 h *= half;
-                let ep = 2_usize.pow(i as u32 - 1);
-                let c = (1..=ep)
-                    .map(|j| closure(*start + X::from(2 * j - 1).unwrap() * *step))
-                    .sum::<X>();
-                rc[0] = h * c + half * rp[0];
-
-                (1..=i).for_each(|j| {
-                    let n_k = X::from(4_u32.pow(j as u32)).unwrap();
-                    rc[j] = (n_k * rc[j - 1] - rp[j - 1]) / (n_k - X::one());
-                });
-            });
-            if *max_steps % 2 == 0 {
-                r1[*max_steps - 1]
-            } else {
-                r2[*max_steps - 1]
+                let n_seg = 1 << level;
+                let c = (1..n_seg)
+                    .step_by(2)
+                    .map(|j| closure(grid.at_fraction(j, n_seg)))
+                    .sum_with(summation);
+                row = richardson_row(&row, half * row[0] + h * c, 2, 2);
             }
+            row[max_steps - 1]
+        }
+        #[cfg(feature = "filon")]
+        ComputeMethod::Filon { omega, kind } => {
+            let vals: Vec<_> = (0..n_step).map(|i| closure(grid.node(i))).collect();
+            oscillatory::filon(&vals, &grid, X::from(omega).unwrap(), kind, summation)
+        }
+        #[cfg(feature = "gregory")]
+        ComputeMethod::Gregory { order } => {
+            let vals: Vec<_> = (0..n_step).map(|i| closure(grid.node(i))).collect();
+            gregory(&vals, step, order, summation)
+        }
+        #[cfg(feature = "richardson")]
+        ComputeMethod::Richardson {
+            base,
+            levels,
+            tolerance,
+        } => richardson(
+            &closure,
+            &grid,
+            base,
+            levels,
+            X::from(tolerance).unwrap(),
+            summation,
+        )?,
+        #[cfg(feature = "open")]
+        ComputeMethod::Midpoint => open_rule(closure, |i| grid.node(i), n_step, 1, summation),
+        #[cfg(feature = "open")]
+        ComputeMethod::OpenNewtonCotes { n_points } => {
+            open_rule(closure, |i| grid.node(i), n_step, n_points, summation)
+        }
+        // rejected during validation
+        #[cfg(feature = "simplex")]
+        ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. } => unreachable!(),
+        #[cfg(feature = "polar")]
+        ComputeMethod::Lebedev { .. }
+        | ComputeMethod::SphericalProduct { .. }
+        | ComputeMethod::PolarGauss { .. } => unreachable!(),
+        #[cfg(feature = "sparse")]
+        ComputeMethod::Smolyak { .. } => unreachable!(),
+        #[cfg(feature = "adaptive")]
+        ComputeMethod::GenzMalik { .. } => unreachable!(),
+        #[cfg(feature = "levin")]
+        ComputeMethod::Levin { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "non-oscillatory function descriptors",
+            });
+        }
+        // only the limits of the domain are used; see `closure_arm`
+        #[cfg(feature = "singular")]
+        ComputeMethod::CauchyPrincipalValue { .. } | ComputeMethod::AlgebraicLogarithmic { .. } => {
+            unreachable!()
         }
         #[cfg(feature = "montecarlo")]
         ComputeMethod::MonteCarlo { n_sample } => {
             // FIXME: nuke this temp allocation
-            let vals: Vec<_> = (0..*n_step)
-                .map(|i| closure(*start + X::from(i).unwrap() * *step))
-                .collect();
+            let vals: Vec<_> = (0..n_step).map(|i| closure(grid.node(i))).collect();
             let (mut min, mut max) = (vals[0], vals[0]);
             for v in &vals {
                 min = min.min(*v);
                 max = max.max(*v);
             }
             let intervals = vals.iter().map(|v| v.min(X::zero())..v.max(X::zero()));
-            let volume: X = (max - min) * (X::from(*n_step).unwrap() * *step);
-            let mut rng = rand::thread_rng();
+            let volume: X = (max - min) * grid.length();
+            let mut rng = rand::rng();
             let random_numbers: Vec<X> = (&mut rng)
                 .sample_iter(
                     rand::distr::Uniform::new(min.to_f64().unwrap(), max.to_f64().unwrap())
                         .unwrap(),
                 )
-                .take(*n_sample * *n_step)
+                .take(n_sample * n_step)
                 .map(|s| X::from(s).unwrap())
                 .collect();
             let total_in: usize = random_numbers
-                .chunks_exact(*n_sample)
+                .chunks_exact(n_sample)
                 .zip(intervals)
                 .map(|(samples, range)| samples.iter().filter(|s| range.contains(s)).count())
                 .sum();
-            volume * X::from(total_in as f64 / (*n_sample * *n_step) as f64).unwrap()
+            volume * X::from(total_in as f64 / (n_sample * n_step) as f64).unwrap()
         }
     };
 
     Ok(res)
 }
+
+// apply Gregory's endpoint corrections to the trapezoid rule; the correction of order `k` is
+// `-h * |G_k+1| * (nabla^k f_n + (-1)^k * delta^k f_0)`, `G` being Gregory coefficients
+#[cfg(feature = "gregory")]
+fn gregory<X: Scalar>(vals: &[X], step: X, order: usize, summation: Summation) -> X {
+    const COEFFICIENTS: [f64; 8] = [
+        1.0 / 12.0,
+        1.0 / 24.0,
+        19.0 / 720.0,
+        3.0 / 160.0,
+        863.0 / 60480.0,
+        275.0 / 24192.0,
+        33953.0 / 3_628_800.0,
+        8183.0 / 1_036_800.0,
+    ];
+    // expanding differences, the weight of f_j & f_n-j is corrected by
+    // -(-1)^j * sum_k |G_k+1| * binomial(k, j)
+    let binomial =
+        |k: usize, j: usize| (0..j).fold(1.0, |acc, i| acc * (k - i) as f64 / (i + 1) as f64);
+    let corrections: Vec<X> = (0..=order)
+        .map(|j| {
+            let correction: f64 = (j.max(1)..=order)
+                .map(|k| COEFFICIENTS[k - 1] * binomial(k, j))
+                .sum();
+            let sign = if j.is_multiple_of(2) { -1.0 } else { 1.0 };
+            X::from(sign * correction).unwrap()
+        })
+        .collect();
+
+    let n = vals.len() - 1;
+    let half = X::from(0.5).unwrap();
+    let trapezoid = vals.iter().copied().sum_with(summation) - half * (vals[0] + vals[n]);
+    let correction = corrections
+        .iter()
+        .enumerate()
+        .map(|(j, c)| *c * (vals[j] + vals[n - j]))
+        .sum_with(summation);
+    step * (trapezoid + correction)
+}
+
+// apply an open Newton-Cotes rule of `n_points` points over each segment of the domain; the
+// function is never evaluated at the nodes of the domain
+#[cfg(feature = "open")]
+fn open_rule<X: Scalar>(
+    closure: impl Fn(X) -> X,
+    node: impl Fn(usize) -> X,
+    n_sample: usize,
+    n_points: usize,
+    summation: Summation,
+) -> X {
+    // weights, in units of the distance between points
+    const WEIGHTS: [&[f64]; 5] = [
+        &[2.0],
+        &[1.5, 1.5],
+        &[8.0 / 3.0, -4.0 / 3.0, 8.0 / 3.0],
+        &[55.0 / 24.0, 5.0 / 24.0, 5.0 / 24.0, 55.0 / 24.0],
+        &[3.3, -4.2, 7.8, -4.2, 3.3],
+    ];
+    let weights: Vec<X> = WEIGHTS[n_points - 1]
+        .iter()
+        .map(|w| X::from(*w).unwrap())
+        .collect();
+    let n_intervals = X::from(n_points + 1).unwrap();
+    (1..n_sample)
+        .map(|i| {
+            let (start, end) = (node(i - 1), node(i));
+            let delta = (end - start) / n_intervals;
+            delta
+                * weights
+                    .iter()
+                    .enumerate()
+                    .map(|(k, w)| *w * closure(start + delta * X::from(k + 1).unwrap()))
+                    .sum_with(summation)
+        })
+        .sum_with(summation)
+}
+
+// compute the next row of a Richardson tableau, given the estimate of a rule of error order
+// `order` with its step divided by `ratio`; Romberg's method halves the step of the trapezoid
+// rule, of order 2
+#[cfg(any(feature = "romberg", feature = "richardson"))]
+fn richardson_row<X: Scalar>(prev: &[X], estimate: X, order: usize, ratio: usize) -> Vec<X> {
+    let ratio = X::from(ratio).unwrap();
+    let mut row = Vec::with_capacity(prev.len() + 1);
+    row.push(estimate);
+    // errors of the rules only contain even powers of the step
+    let mut factor = (0..order).fold(X::one(), |acc, _| acc * ratio);
+    for (j, p) in prev.iter().enumerate() {
+        row.push((factor * row[j] - *p) / (factor - X::one()));
+        factor *= ratio * ratio;
+    }
+    row
+}
+
+// refine the step of the base rule until successive extrapolated estimates agree
+#[cfg(feature = "richardson")]
+fn richardson<X: Scalar>(
+    closure: impl Fn(X) -> X,
+    grid: &UniformGrid<X>,
+    base: BaseRule,
+    levels: usize,
+    tolerance: X,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    let evaluations = Cell::new(0);
+    let f = |x: X| {
+        evaluations.set(evaluations.get() + 1);
+        closure(x)
+    };
+    let half = X::from(0.5).unwrap();
+
+    // trapezoid estimates are refined in place, and reused by Simpson's rule
+    let (mut trapezoid, mut n_seg) = (X::zero(), 1);
+    let (mut midpoint, mut n_mid) = (X::zero(), 1);
+    let init = |trapezoid: &mut X| {
+        *trapezoid = half * grid.length() * (f(grid.start()) + f(grid.end()));
+    };
+    let refine = |trapezoid: &mut X, n_seg: &mut usize| {
+        let c = (1..2 * *n_seg)
+            .step_by(2)
+            .map(|j| f(grid.at_fraction(j, 2 * *n_seg)))
+            .sum_with(summation);
+        *n_seg *= 2;
+        *trapezoid = half * *trapezoid + grid.length() / X::from(*n_seg).unwrap() * c;
+    };
+    let mut estimate = |level: usize| match base {
+        BaseRule::Trapezoid => {
+            if level == 0 {
+                init(&mut trapezoid);
+            } else {
+                refine(&mut trapezoid, &mut n_seg);
+            }
+            trapezoid
+        }
+        BaseRule::Simpson => {
+            if level == 0 {
+                init(&mut trapezoid);
+            }
+            let coarse = trapezoid;
+            refine(&mut trapezoid, &mut n_seg);
+            (X::from(4.0).unwrap() * trapezoid - coarse) / X::from(3.0).unwrap()
+        }
+        // midpoints of the current segments are also midpoints once the step is divided by 3
+        BaseRule::Midpoint => {
+            if level == 0 {
+                midpoint = grid.length() * f(grid.at_fraction(1, 2));
+            } else {
+                let c = (0..n_mid)
+                    .flat_map(|j| [6 * j + 1, 6 * j + 5])
+                    .map(|k| f(grid.at_fraction(k, 6 * n_mid)))
+                    .sum_with(summation);
+                n_mid *= 3;
+                midpoint =
+                    midpoint / X::from(3.0).unwrap() + grid.length() / X::from(n_mid).unwrap() * c;
+            }
+            midpoint
+        }
+    };
+    let (order, ratio) = match base {
+        BaseRule::Midpoint => (2, 3),
+        BaseRule::Trapezoid => (2, 2),
+        BaseRule::Simpson => (4, 2),
+    };
+
+    let mut row = vec![estimate(0)];
+    let mut error = X::infinity();
+    for level in 1..levels {
+        let next = richardson_row(&row, estimate(level), order, ratio);
+        error = (next[level] - row[level - 1]).abs();
+        row = next;
+        if error <= tolerance {
+            return Ok(row[level]);
+        }
+    }
+    Err(IntegraalError::NotConverged {
+        achieved_error: error.to_f64().unwrap(),
+        evaluations: evaluations.get(),
+    })
+}
@@ -0,0 +1,66 @@
+//! iterated integration code
+
+// ------ IMPORTS
+
+use super::validation;
+use crate::{ComputeMethod, DomainDescriptor, IntegraalError, IteratedLevel, Scalar};
+
+// ------ CONTENT
+
+/// Check that the levels fit the requirements of their methods.
+///
+/// As limits depend on outer variables, each level is checked over its domain at the center of
+/// the outer levels; levels that are degenerate there are only checked during computation.
+pub(crate) fn validate<X: Scalar>(
+    levels: &[IteratedLevel<X>],
+    method: ComputeMethod,
+    allow_descending: bool,
+) -> Result<(), IntegraalError> {
+    if levels.is_empty() {
+        return Err(IntegraalError::BadParameters(String::from(
+            "an iterated integral should have at least one level",
+        )));
+    }
+    let mut center = Vec::with_capacity(levels.len());
+    for level in levels {
+        let method = level.method.as_ref().unwrap_or(&method);
+        let Some(domain) = domain(level, &center, allow_descending)? else {
+            center.push((level.lower)(&center));
+            continue;
+        };
+        validation::validate(&domain, *method, allow_descending)?;
+        let (start, end) = (domain.abscissa(0), domain.abscissa(level.n_sample - 1));
+        center.push((start + end) / (X::one() + X::one()));
+    }
+    Ok(())
+}
+
+/// Return the domain of the level given the values of the outer variables, or `None` if its
+/// limits are equal.
+pub(crate) fn domain<X: Scalar>(
+    level: &IteratedLevel<X>,
+    outer: &[X],
+    allow_descending: bool,
+) -> Result<Option<DomainDescriptor<X>>, IntegraalError> {
+    let (lower, upper) = ((level.lower)(outer), (level.upper)(outer));
+    if !(lower.is_finite() && upper.is_finite()) {
+        return Err(IntegraalError::BadParameters(format!(
+            "iterated integral limits should be finite (level {}: {lower:?} and {upper:?}, outer variables: {outer:?})",
+            outer.len()
+        )));
+    }
+    if lower == upper {
+        return Ok(None);
+    }
+    if upper < lower && !allow_descending {
+        return Err(IntegraalError::BadParameters(format!(
+            "iterated integral limits should be in increasing order (level {}: {lower:?} and {upper:?}, outer variables: {outer:?})",
+            outer.len()
+        )));
+    }
+    Ok(Some(DomainDescriptor::linspace(
+        lower,
+        upper,
+        level.n_sample,
+    )))
+}
@@ -0,0 +1,95 @@
+//! dense linear algebra code
+
+// ------ IMPORTS
+
+use crate::Scalar;
+
+// ------ CONTENT
+
+/// Solve the square linear system `a * x = b`, where `a` is stored in row-major order.
+///
+/// This uses Gaussian elimination with partial pivoting; `None` is returned if the matrix is
+/// singular to working precision.
+#[cfg(any(feature = "levin", feature = "sparse"))]
+pub(crate) fn solve<X: Scalar>(mut a: Vec<X>, mut b: Vec<X>) -> Option<Vec<X>> {
+    let n = b.len();
+    debug_assert_eq!(a.len(), n * n);
+    let scale = a.iter().fold(X::zero(), |acc, v| acc.max(v.abs()));
+    let threshold = scale * X::epsilon() * X::from(n).unwrap();
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|i, j| {
+                a[i * n + col]
+                    .abs()
+                    .partial_cmp(&a[j * n + col].abs())
+                    .unwrap()
+            })
+            .unwrap();
+        let pivot_value = a[pivot * n + col].abs();
+        if pivot_value.is_nan() || pivot_value <= threshold {
+            return None;
+        }
+        if pivot != col {
+            for k in 0..n {
+                a.swap(pivot * n + k, col * n + k);
+            }
+            b.swap(pivot, col);
+        }
+        for row in col + 1..n {
+            let factor = a[row * n + col] / a[col * n + col];
+            if factor.is_zero() {
+                continue;
+            }
+            for k in col..n {
+                let v = a[col * n + k];
+                a[row * n + k] = a[row * n + k] - factor * v;
+            }
+            b[row] = b[row] - factor * b[col];
+        }
+    }
+
+    let mut x = vec![X::zero(); n];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(b[row], |acc, k| acc - a[row * n + k] * x[k]);
+        x[row] = sum / a[row * n + row];
+    }
+    Some(x)
+}
+
+/// Compute the determinant of the square matrix `a`, stored in row-major order.
+///
+/// This uses Gaussian elimination with partial pivoting.
+#[cfg(feature = "simplex")]
+pub(crate) fn determinant<X: Scalar>(mut a: Vec<X>, n: usize) -> X {
+    debug_assert_eq!(a.len(), n * n);
+    let mut det = X::one();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|i, j| {
+                a[i * n + col]
+                    .abs()
+                    .partial_cmp(&a[j * n + col].abs())
+                    .unwrap()
+            })
+            .unwrap();
+        if a[pivot * n + col].is_zero() {
+            return X::zero();
+        }
+        if pivot != col {
+            for k in 0..n {
+                a.swap(pivot * n + k, col * n + k);
+            }
+            det = -det;
+        }
+        det *= a[col * n + col];
+        for row in col + 1..n {
+            let factor = a[row * n + col] / a[col * n + col];
+            for k in col..n {
+                let v = a[col * n + k];
+                a[row * n + k] = a[row * n + k] - factor * v;
+            }
+        }
+    }
+    det
+}
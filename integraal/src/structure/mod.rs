@@ -2,9 +2,40 @@
 
 // ------ MODULE DECLARATIONS
 
+#[cfg(feature = "adaptive")]
+mod adaptive;
+mod builder;
+mod cubature;
+mod curve;
 mod definitions;
+mod detection;
+mod grid;
 mod implementations;
+mod iterated;
+#[cfg(any(feature = "levin", feature = "simplex", feature = "sparse"))]
+mod linalg;
+mod nodes;
+mod non_finite;
+mod oscillatory;
+#[cfg(feature = "polar")]
+mod polar;
+mod scattered;
+#[cfg(feature = "simplex")]
+mod simplex;
+#[cfg(feature = "singular")]
+mod singular;
+#[cfg(feature = "sparse")]
+mod sparse;
+mod summation;
+mod surface;
+mod validation;
 
 // ------ RE-EXPORTS
 
-pub use definitions::{Integraal, IntegraalError};
+pub(crate) use grid::UniformGrid;
+pub(crate) use nodes::Nodes;
+
+pub use builder::{IntegraalBuilder, Unset};
+pub use definitions::{
+    Breakpoint, Comparison, Discontinuity, Evaluation, Integraal, IntegraalError,
+};
@@ -0,0 +1,127 @@
+//! node generation code
+
+// ------ IMPORTS
+
+use crate::{parameters::NodeGenerator, Scalar, Spacing};
+
+// ------ CONTENT
+
+/// Read-only access to the abscissae of a non-uniform domain.
+///
+/// This allows explicit and generated domains to share computation code, without materializing
+/// generated nodes.
+pub(crate) trait Nodes<X: Scalar> {
+    /// Return the number of nodes.
+    fn n_nodes(&self) -> usize;
+
+    /// Return the `idx`-th node.
+    fn node(&self, idx: usize) -> X;
+}
+
+impl<X: Scalar> Nodes<X> for [X] {
+    fn n_nodes(&self) -> usize {
+        self.len()
+    }
+
+    fn node(&self, idx: usize) -> X {
+        self[idx]
+    }
+}
+
+impl<X: Scalar> Nodes<X> for NodeGenerator<X> {
+    fn n_nodes(&self) -> usize {
+        self.n_sample
+    }
+
+    fn node(&self, idx: usize) -> X {
+        let (start, end, n) = (self.start, self.end, self.n_sample);
+        // endpoints are returned exactly if they belong to the nodes
+        match self.spacing {
+            Spacing::Linear | Spacing::Geometric | Spacing::ChebyshevLobatto if idx == 0 => {
+                return start;
+            }
+            Spacing::Linear | Spacing::Geometric | Spacing::ChebyshevLobatto if idx + 1 == n => {
+                return end;
+            }
+            _ => {}
+        }
+        let t = X::from(idx).unwrap() / X::from(n.saturating_sub(1).max(1)).unwrap();
+        match self.spacing {
+            Spacing::Linear => start + (end - start) * t,
+            Spacing::Geometric => start * (end / start).powf(t),
+            Spacing::ChebyshevGauss => map_reference(start, end, chebyshev_gauss(n, idx)),
+            Spacing::ChebyshevLobatto => map_reference(start, end, chebyshev_lobatto(n, idx)),
+            Spacing::GaussLegendre => map_reference(start, end, gauss_legendre(n, idx).0),
+        }
+    }
+}
+
+fn pi<X: Scalar>() -> X {
+    X::from(std::f64::consts::PI).unwrap()
+}
+
+/// Map a point of the reference interval `[-1, 1]` to `[start, end]`.
+pub(crate) fn map_reference<X: Scalar>(start: X, end: X, t: X) -> X {
+    let half = X::from(0.5).unwrap();
+    start + (end - start) * (t + X::one()) * half
+}
+
+/// Return the `idx`-th (in increasing order) of the `n` Chebyshev-Gauss nodes over `[-1, 1]`.
+pub(crate) fn chebyshev_gauss<X: Scalar>(n: usize, idx: usize) -> X {
+    let theta = X::from(2 * idx + 1).unwrap() * pi::<X>() / X::from(2 * n).unwrap();
+    -theta.cos()
+}
+
+/// Return the `idx`-th (in increasing order) of the `n` Chebyshev-Lobatto nodes over `[-1, 1]`.
+pub(crate) fn chebyshev_lobatto<X: Scalar>(n: usize, idx: usize) -> X {
+    let theta = X::from(idx).unwrap() * pi::<X>() / X::from(n.saturating_sub(1).max(1)).unwrap();
+    -theta.cos()
+}
+
+/// Return the value of the Legendre polynomial of degree `n` at `x`, as well as its derivative.
+pub(crate) fn legendre<X: Scalar>(n: usize, x: X) -> (X, X) {
+    let (mut p_prev, mut p) = (X::one(), x);
+    if n == 0 {
+        return (X::one(), X::zero());
+    }
+    for k in 2..=n {
+        let k = X::from(k).unwrap();
+        let p_next = ((k + k - X::one()) * x * p - (k - X::one()) * p_prev) / k;
+        p_prev = p;
+        p = p_next;
+    }
+    let dp = X::from(n).unwrap() * (x * p - p_prev) / (x * x - X::one());
+    (p, dp)
+}
+
+/// Return the `idx`-th (in increasing order) of the `n` Gauss-Legendre nodes over `[-1, 1]`,
+/// as well as its weight.
+///
+/// The node is computed using Newton's method on the Legendre polynomial, starting from an
+/// asymptotic approximation; each call costs `O(n)` operations per iteration.
+pub(crate) fn gauss_legendre<X: Scalar>(n: usize, idx: usize) -> (X, X) {
+    // roots are symmetric; we compute the non-positive one & flip it if needed
+    let (k, flip) = if 2 * idx + 1 > n {
+        (n - 1 - idx, true)
+    } else {
+        (idx, false)
+    };
+    let mut x = -(pi::<X>() * (X::from(k).unwrap() + X::from(0.75).unwrap())
+        / (X::from(n).unwrap() + X::from(0.5).unwrap()))
+    .cos();
+    if 2 * k + 1 == n {
+        x = X::zero();
+    } else {
+        for _ in 0..100 {
+            let (p, dp) = legendre(n, x);
+            let dx = p / dp;
+            x = x - dx;
+            if dx.abs() <= X::epsilon() {
+                break;
+            }
+        }
+    }
+    let (_, dp) = legendre(n, x);
+    let weight = X::from(2.0).unwrap() / ((X::one() - x * x) * dp * dp);
+    (if flip { -x } else { x }, weight)
+}
@@ -0,0 +1,122 @@
+//! non-finite value handling code
+
+// ------ IMPORTS
+
+use crate::{DomainDescriptor, IntegraalError, NonFinitePolicy, Scalar};
+
+// ------ CONTENT
+
+/// Sanitized function values, and the domain they are defined on if it had to be rebuilt.
+pub(crate) type Sanitized<X> = (Vec<X>, Option<Vec<X>>);
+
+/// Return the index of the domain sample closest to `x`.
+pub(crate) fn sample_index<X: Scalar>(domain: &DomainDescriptor<X>, x: X) -> usize {
+    match domain {
+        DomainDescriptor::Uniform { start, step, .. } => {
+            ((x - *start) / *step).round().to_usize().unwrap_or(0)
+        }
+        DomainDescriptor::Explicit(_)
+        | DomainDescriptor::Generated(_)
+        | DomainDescriptor::Piecewise(_) => (0..domain.n_sample())
+            .min_by(|a, b| {
+                let (a, b) = (domain.abscissa(*a), domain.abscissa(*b));
+                (a - x).abs().partial_cmp(&(b - x).abs()).unwrap()
+            })
+            .unwrap_or(0),
+    }
+}
+
+/// Build the error corresponding to a faulty `idx`-th sample.
+pub(crate) fn error<X: Scalar>(domain: &DomainDescriptor<X>, idx: usize) -> IntegraalError {
+    IntegraalError::NonFinite {
+        index: idx,
+        abscissa: domain.abscissa(idx).to_f64().unwrap(),
+    }
+}
+
+/// Build the error corresponding to a faulty value at `x`.
+///
+/// Points located between samples, e.g. evaluated by methods that subdivide the domain, are
+/// reported along with the index of the sample that precedes them.
+pub(crate) fn error_at<X: Scalar>(domain: &DomainDescriptor<X>, x: X) -> IntegraalError {
+    let idx = sample_index(domain, x).min(domain.n_sample() - 1);
+    let sample = domain.abscissa(idx);
+    if sample == x {
+        return error(domain, idx);
+    }
+    IntegraalError::NonFinite {
+        index: if x < sample {
+            idx.saturating_sub(1)
+        } else {
+            idx
+        },
+        abscissa: x.to_f64().unwrap(),
+    }
+}
+
+/// Check function values according to the policy.
+///
+/// This returns `Ok(None)` if values can be used as-is, or sanitized values otherwise. The
+/// domain is returned as an explicit list of values if samples had to be removed.
+pub(crate) fn sanitize<X: Scalar>(
+    vals: &[X],
+    domain: &DomainDescriptor<X>,
+    policy: NonFinitePolicy,
+) -> Result<Option<Sanitized<X>>, IntegraalError> {
+    let Some(first) = vals.iter().position(|v| !v.is_finite()) else {
+        return Ok(None);
+    };
+
+    let res = match policy {
+        NonFinitePolicy::Error => return Err(error(domain, first)),
+        NonFinitePolicy::Skip => {
+            let (args, vals) = vals
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| v.is_finite())
+                .map(|(idx, v)| (domain.abscissa(idx), *v))
+                .unzip();
+            (vals, Some(args))
+        }
+        NonFinitePolicy::ReplaceWithZero => (
+            vals.iter()
+                .map(|v| if v.is_finite() { *v } else { X::zero() })
+                .collect(),
+            None,
+        ),
+        NonFinitePolicy::Interpolate => {
+            let finite: Vec<usize> = (0..vals.len())
+                .filter(|idx| vals[*idx].is_finite())
+                .collect();
+            if finite.is_empty() {
+                return Err(error(domain, first));
+            }
+            // `next` is the position of the closest finite sample on the right in `finite`
+            let mut next: usize = 0;
+            let new_vals = (0..vals.len())
+                .map(|idx| {
+                    if vals[idx].is_finite() {
+                        next += 1;
+                        return vals[idx];
+                    }
+                    match (
+                        next.checked_sub(1).map(|p| finite[p]),
+                        finite.get(next).copied(),
+                    ) {
+                        (Some(lo), Some(hi)) => {
+                            let (x_lo, x_hi) = (domain.abscissa(lo), domain.abscissa(hi));
+                            let t = (domain.abscissa(idx) - x_lo) / (x_hi - x_lo);
+                            vals[lo] + t * (vals[hi] - vals[lo])
+                        }
+                        (Some(lo), None) => vals[lo],
+                        (None, Some(hi)) => vals[hi],
+                        (None, None) => unreachable!(),
+                    }
+                })
+                .collect();
+            (new_vals, None)
+        }
+    };
+
+    Ok(Some(res))
+}
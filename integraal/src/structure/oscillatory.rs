@@ -0,0 +1,162 @@
+//! oscillatory integral code
+
+// ------ IMPORTS
+
+#[cfg(feature = "filon")]
+use super::{grid::UniformGrid, summation::SumWith};
+#[cfg(feature = "levin")]
+use super::{linalg, nodes};
+#[cfg(feature = "levin")]
+use crate::IntegraalError;
+#[cfg(feature = "filon")]
+use crate::Summation;
+use crate::{Oscillator, Scalar};
+
+// ------ CONTENT
+
+/// Evaluate the oscillator at `x`.
+pub(crate) fn oscillator<X: Scalar>(kind: Oscillator, x: X) -> X {
+    match kind {
+        Oscillator::Sin => x.sin(),
+        Oscillator::Cos => x.cos(),
+    }
+}
+
+/// Compute the integral of `f(x) * sin(omega * x)` or `f(x) * cos(omega * x)` using Filon's
+/// method, from values of `f` over a uniform grid made of an even number of segments.
+///
+/// `f` is interpolated by a quadratic over each pair of segments, and the product with the
+/// oscillator is integrated exactly; the accuracy of the method does not depend on `omega`.
+#[cfg(feature = "filon")]
+pub(crate) fn filon<X: Scalar>(
+    vals: &[X],
+    grid: &UniformGrid<X>,
+    omega: X,
+    kind: Oscillator,
+    summation: Summation,
+) -> X {
+    let (alpha, beta, gamma) = filon_coefficients(omega * grid.step());
+    let n_seg = grid.n_segment();
+    let (a, b) = (omega * grid.start(), omega * grid.end());
+    let term = |i: usize| vals[i] * oscillator(kind, omega * grid.node(i));
+    let half = X::from(0.5).unwrap();
+
+    let even =
+        (0..=n_seg).step_by(2).map(term).sum_with(summation) - half * (term(0) + term(n_seg));
+    let odd = (1..n_seg).step_by(2).map(term).sum_with(summation);
+    let boundary = match kind {
+        Oscillator::Cos => vals[n_seg] * b.sin() - vals[0] * a.sin(),
+        Oscillator::Sin => vals[0] * a.cos() - vals[n_seg] * b.cos(),
+    };
+    grid.step() * (alpha * boundary + beta * even + gamma * odd)
+}
+
+/// Return the `(alpha, beta, gamma)` coefficients of Filon's method, for `theta = omega * h`.
+#[cfg(feature = "filon")]
+fn filon_coefficients<X: Scalar>(theta: X) -> (X, X, X) {
+    let c = |v: f64| X::from(v).unwrap();
+    if theta.abs() < c(1.0 / 6.0) {
+        // series expansions, avoiding cancellations
+        let t2 = theta * theta;
+        let alpha = theta * t2 * (c(2.0 / 45.0) - t2 * (c(2.0 / 315.0) - t2 * c(2.0 / 4725.0)));
+        let beta =
+            c(2.0 / 3.0) + t2 * (c(2.0 / 15.0) - t2 * (c(4.0 / 105.0) - t2 * c(2.0 / 567.0)));
+        let gamma =
+            c(4.0 / 3.0) - t2 * (c(2.0 / 15.0) - t2 * (c(1.0 / 210.0) - t2 * c(1.0 / 11340.0)));
+        (alpha, beta, gamma)
+    } else {
+        let (sin, cos) = theta.sin_cos();
+        let t3 = theta * theta * theta;
+        let alpha = (theta * theta + theta * sin * cos - c(2.0) * sin * sin) / t3;
+        let beta = c(2.0) * (theta * (X::one() + cos * cos) - c(2.0) * sin * cos) / t3;
+        let gamma = c(4.0) * (sin - theta * cos) / t3;
+        (alpha, beta, gamma)
+    }
+}
+
+/// Compute the integral of `amplitude(x) * e^{i * phase(x)}` over `[start, end]` using Levin's
+/// collocation method, and return its real (`Cos`) or imaginary (`Sin`) part.
+///
+/// We look for a non-oscillatory `p` such that `p' + i * phase' * p = amplitude`, so that the
+/// integral is equal to `p(end) * e^{i * phase(end)} - p(start) * e^{i * phase(start)}`. Real and
+/// imaginary parts of `p` are expanded over Chebyshev polynomials, and the equation is enforced
+/// at `n_nodes` Chebyshev-Lobatto nodes.
+#[cfg(feature = "levin")]
+#[allow(clippy::similar_names)]
+pub(crate) fn levin<X: Scalar>(
+    amplitude: &dyn Fn(X) -> X,
+    phase: &dyn Fn(X) -> X,
+    phase_derivative: &dyn Fn(X) -> X,
+    kind: Oscillator,
+    (start, end): (X, X),
+    n_nodes: usize,
+) -> Result<X, IntegraalError> {
+    let n = n_nodes;
+    let half_length = (end - start) * X::from(0.5).unwrap();
+
+    // unknowns: u_0..u_n-1 (real part), v_0..v_n-1 (imaginary part)
+    // equations: u' - phase' * v = amplitude, v' + phase' * u = 0
+    let mut matrix = vec![X::zero(); 4 * n * n];
+    let mut rhs = vec![X::zero(); 2 * n];
+    for j in 0..n {
+        let t = nodes::chebyshev_lobatto(n, j);
+        let node = nodes::map_reference(start, end, t);
+        let dg = phase_derivative(node);
+        let (t_k, dt_k) = chebyshev(n, t);
+        for k in 0..n {
+            let d = dt_k[k] / half_length;
+            matrix[j * 2 * n + k] = d;
+            matrix[j * 2 * n + n + k] = -dg * t_k[k];
+            matrix[(n + j) * 2 * n + k] = dg * t_k[k];
+            matrix[(n + j) * 2 * n + n + k] = d;
+        }
+        rhs[j] = amplitude(node);
+    }
+    let Some(coeffs) = linalg::solve(matrix, rhs) else {
+        return Err(IntegraalError::BadParameters(String::from(
+            "Levin's method collocation system is singular; the phase may have stationary points",
+        )));
+    };
+
+    let primitive = |t: X, limit: X| {
+        let (t_k, _) = chebyshev(n, t);
+        let u = (0..n).fold(X::zero(), |acc, k| acc + coeffs[k] * t_k[k]);
+        let v = (0..n).fold(X::zero(), |acc, k| acc + coeffs[n + k] * t_k[k]);
+        let (sin, cos) = phase(limit).sin_cos();
+        match kind {
+            Oscillator::Cos => u * cos - v * sin,
+            Oscillator::Sin => u * sin + v * cos,
+        }
+    };
+    Ok(primitive(X::one(), end) - primitive(-X::one(), start))
+}
+
+/// Return the values of the first `n` Chebyshev polynomials & of their derivatives at `t`.
+#[cfg(feature = "levin")]
+fn chebyshev<X: Scalar>(n: usize, t: X) -> (Vec<X>, Vec<X>) {
+    let two = X::from(2.0).unwrap();
+    // T_k' = k * U_k-1, where U is the Chebyshev polynomial of the second kind
+    let (mut t_k, mut u_k) = (Vec::with_capacity(n), Vec::with_capacity(n));
+    for k in 0..n {
+        let (t_next, u_next) = match k {
+            0 => (X::one(), X::one()),
+            1 => (t, two * t),
+            _ => (
+                two * t * t_k[k - 1] - t_k[k - 2],
+                two * t * u_k[k - 1] - u_k[k - 2],
+            ),
+        };
+        t_k.push(t_next);
+        u_k.push(u_next);
+    }
+    let dt_k = (0..n)
+        .map(|k| {
+            if k == 0 {
+                X::zero()
+            } else {
+                X::from(k).unwrap() * u_k[k - 1]
+            }
+        })
+        .collect();
+    (t_k, dt_k)
+}
@@ -0,0 +1,141 @@
+//! sphere, disc & annulus integration code
+
+// ------ IMPORTS
+
+use super::nodes;
+use crate::Scalar;
+
+// ------ CONTENT
+
+/// Numbers of points of the available Lebedev rules.
+pub(crate) const LEBEDEV_SIZES: [usize; 5] = [6, 14, 26, 38, 50];
+
+/// Orbit of points of a Lebedev rule, invariant under the symmetries of the octahedron.
+enum Orbit {
+    /// Points `(1, 0, 0)`, and their 6 signed permutations.
+    A1(f64),
+    /// Points `(1, 1, 0) / sqrt(2)`, and their 12 signed permutations.
+    A2(f64),
+    /// Points `(1, 1, 1) / sqrt(3)`, and their 8 signed permutations.
+    A3(f64),
+    /// Points `(l, l, m)`, where `m = sqrt(1 - 2l^2)`, and their 24 signed permutations.
+    B(f64, f64),
+    /// Points `(p, q, 0)`, where `q = sqrt(1 - p^2)`, and their 24 signed permutations.
+    C(f64, f64),
+}
+
+/// Return the Lebedev rule of the given number of points over the unit sphere; weights sum to
+/// the area of the sphere.
+pub(crate) fn lebedev<X: Scalar>(n_points: usize) -> Vec<([X; 3], X)> {
+    use Orbit::{A1, A2, A3, B, C};
+    let orbits: &[Orbit] = match n_points {
+        6 => &[A1(1.0 / 6.0)],
+        14 => &[A1(1.0 / 15.0), A3(3.0 / 40.0)],
+        26 => &[A1(1.0 / 21.0), A2(4.0 / 105.0), A3(9.0 / 280.0)],
+        38 => &[
+            A1(1.0 / 105.0),
+            A3(9.0 / 280.0),
+            C(0.459_700_843_380_983_1, 1.0 / 35.0),
+        ],
+        50 => &[
+            A1(4.0 / 315.0),
+            A2(64.0 / 2835.0),
+            A3(27.0 / 1280.0),
+            B(0.301_511_344_577_763_6, 14641.0 / 725_760.0),
+        ],
+        _ => unreachable!(),
+    };
+
+    // generate the points of an orbit from one of them, by permuting & flipping coordinates
+    let signed_permutations = |p: [f64; 3]| {
+        let mut points: Vec<[f64; 3]> = Vec::new();
+        for [i, j, k] in [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ] {
+            for signs in 0..8 {
+                let sign = |bit: usize| if signs & (1 << bit) == 0 { 1.0 } else { -1.0 };
+                let q = [sign(0) * p[i], sign(1) * p[j], sign(2) * p[k]];
+                if !points.contains(&q) {
+                    points.push(q);
+                }
+            }
+        }
+        points
+    };
+
+    let area = 4.0 * std::f64::consts::PI;
+    let mut rule = Vec::with_capacity(n_points);
+    for orbit in orbits {
+        let (point, weight) = match *orbit {
+            A1(w) => ([1.0, 0.0, 0.0], w),
+            A2(w) => {
+                let s = std::f64::consts::FRAC_1_SQRT_2;
+                ([s, s, 0.0], w)
+            }
+            A3(w) => ([1.0 / 3.0_f64.sqrt(); 3], w),
+            B(l, w) => ([l, l, (1.0 - 2.0 * l * l).sqrt()], w),
+            C(p, w) => ([p, (1.0 - p * p).sqrt(), 0.0], w),
+        };
+        rule.extend(signed_permutations(point).into_iter().map(|q| {
+            (
+                q.map(|x| X::from(x).unwrap()),
+                X::from(area * weight).unwrap(),
+            )
+        }));
+    }
+    rule
+}
+
+/// Return the product of a Gauss-Legendre rule over the cosine of the polar angle, and of an
+/// equispaced rule over the azimuthal angle, over the unit sphere; weights sum to the area of the
+/// sphere.
+///
+/// The rule is exact for polynomials up to degree `min(2 * n_polar - 1, n_azimuthal - 1)`.
+pub(crate) fn spherical_product<X: Scalar>(n_polar: usize, n_azimuthal: usize) -> Vec<([X; 3], X)> {
+    let azimuthal_weight = X::from(std::f64::consts::TAU / n_azimuthal as f64).unwrap();
+    (0..n_polar)
+        .flat_map(|i| {
+            let (cos_theta, w): (X, X) = nodes::gauss_legendre(n_polar, i);
+            let sin_theta = (X::one() - cos_theta * cos_theta).sqrt();
+            (0..n_azimuthal).map(move |j| {
+                let phi = X::from(std::f64::consts::TAU * j as f64 / n_azimuthal as f64).unwrap();
+                (
+                    [sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta],
+                    w * azimuthal_weight,
+                )
+            })
+        })
+        .collect()
+}
+
+/// Return the product of a Gauss-Legendre rule over the radius, and of an equispaced rule over
+/// the angle, over the annulus of given radii centered on the origin; weights include the
+/// Jacobian of polar coordinates, and sum to the area of the annulus.
+///
+/// The rule is exact for polynomials up to degree `min(2 * n_radial - 2, n_angular - 1)`.
+pub(crate) fn polar_gauss<X: Scalar>(
+    (inner, outer): (X, X),
+    n_radial: usize,
+    n_angular: usize,
+) -> Vec<([X; 2], X)> {
+    let half_width = (outer - inner) * X::from(0.5).unwrap();
+    let angular_weight = X::from(std::f64::consts::TAU / n_angular as f64).unwrap();
+    (0..n_radial)
+        .flat_map(|i| {
+            let (t, w): (X, X) = nodes::gauss_legendre(n_radial, i);
+            let r = nodes::map_reference(inner, outer, t);
+            (0..n_angular).map(move |j| {
+                let theta = X::from(std::f64::consts::TAU * j as f64 / n_angular as f64).unwrap();
+                (
+                    [r * theta.cos(), r * theta.sin()],
+                    r * w * half_width * angular_weight,
+                )
+            })
+        })
+        .collect()
+}
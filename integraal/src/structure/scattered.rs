@@ -0,0 +1,301 @@
+//! scattered data integration code
+
+// ------ IMPORTS
+
+use super::summation::SumWith;
+use crate::{IntegraalError, NonFinitePolicy, Scalar, Summation};
+use std::collections::HashMap;
+
+// ------ CONTENT
+
+/// Check that points can be triangulated, and that the clipping polygon is valid.
+pub(crate) fn validate<X: Scalar>(
+    points: &[[X; 2]],
+    clip: Option<&[[X; 2]]>,
+) -> Result<(), IntegraalError> {
+    if points.len() < 3 {
+        return Err(IntegraalError::BadParameters(format!(
+            "scattered data should contain at least 3 points (points: {})",
+            points.len()
+        )));
+    }
+    if let Some(idx) = points
+        .iter()
+        .position(|p| !(p[0].is_finite() && p[1].is_finite()))
+    {
+        return Err(IntegraalError::BadParameters(format!(
+            "scattered points should have finite coordinates (point {idx})"
+        )));
+    }
+    let order = sorted(points, &(0..points.len()).collect::<Vec<_>>());
+    if let Some(w) = order.windows(2).find(|w| points[w[0]] == points[w[1]]) {
+        return Err(IntegraalError::BadParameters(format!(
+            "scattered data contains duplicate points at indices {} and {}",
+            w[0].min(w[1]),
+            w[0].max(w[1])
+        )));
+    }
+    if let Some(clip) = clip {
+        if clip.len() < 3 {
+            return Err(IntegraalError::BadParameters(format!(
+                "a clipping polygon should have at least 3 vertices (vertices: {})",
+                clip.len()
+            )));
+        }
+        if clip.iter().flatten().any(|x| !x.is_finite()) {
+            return Err(IntegraalError::BadParameters(String::from(
+                "clipping polygon vertices should have finite coordinates",
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Compute the integral of the piecewise-linear interpolant of the values over the Delaunay
+/// triangulation of the points, clipped to the polygon if there is one.
+///
+/// Points with non-finite values are removed before triangulation, unless the policy is
+/// [`NonFinitePolicy::Error`].
+pub(crate) fn integrate<X: Scalar>(
+    points: &[[X; 2]],
+    clip: Option<&[[X; 2]]>,
+    vals: &[X],
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<X, IntegraalError> {
+    let kept: Vec<usize> = match vals.iter().position(|v| !v.is_finite()) {
+        Some(index) if policy == NonFinitePolicy::Error => {
+            return Err(IntegraalError::NonFinite {
+                index,
+                abscissa: points[index][0].to_f64().unwrap(),
+            });
+        }
+        Some(_) => (0..points.len()).filter(|i| vals[*i].is_finite()).collect(),
+        None => (0..points.len()).collect(),
+    };
+    let triangles = delaunay(points, &kept).ok_or_else(|| {
+        IntegraalError::BadParameters(String::from(
+            "scattered points with finite values should not all be collinear",
+        ))
+    })?;
+
+    let third = X::from(3.0).unwrap();
+    let terms: Vec<X> = match clip {
+        None => triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let area = cross(points[a], points[b], points[c]) / (X::one() + X::one());
+                area * (vals[a] + vals[b] + vals[c]) / third
+            })
+            .collect(),
+        Some(clip) => {
+            // orient the polygon counter-clockwise, so that clipped areas are positive
+            let clip: Vec<[X; 2]> = if shoelace(clip).0 < X::zero() {
+                clip.iter().rev().copied().collect()
+            } else {
+                clip.to_vec()
+            };
+            triangles
+                .iter()
+                .map(|&[a, b, c]| {
+                    let triangle = [points[a], points[b], points[c]];
+                    let clipped = clip_by_triangle(&clip, triangle);
+                    if clipped.len() < 3 {
+                        return X::zero();
+                    }
+                    // the interpolant is linear, so its integral is its value at the centroid
+                    // times the area
+                    let (area, centroid) = shoelace(&clipped);
+                    if area.is_zero() {
+                        return X::zero();
+                    }
+                    let total = cross(triangle[0], triangle[1], triangle[2]);
+                    let (la, lb) = (
+                        cross(centroid, triangle[1], triangle[2]) / total,
+                        cross(triangle[0], centroid, triangle[2]) / total,
+                    );
+                    area * (la * vals[a] + lb * vals[b] + (X::one() - la - lb) * vals[c])
+                })
+                .collect()
+        }
+    };
+    Ok(terms.into_iter().sum_with(summation))
+}
+
+/// Return twice the signed area of the triangle `(o, a, b)`.
+fn cross<X: Scalar>(o: [X; 2], a: [X; 2], b: [X; 2]) -> X {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+/// Return the signed area & the centroid of a polygon.
+fn shoelace<X: Scalar>(polygon: &[[X; 2]]) -> (X, [X; 2]) {
+    let (area, cx, cy) = polygon.iter().enumerate().fold(
+        (X::zero(), X::zero(), X::zero()),
+        |(area, cx, cy), (i, p)| {
+            let q = polygon[(i + 1) % polygon.len()];
+            let c = p[0] * q[1] - q[0] * p[1];
+            (area + c, cx + (p[0] + q[0]) * c, cy + (p[1] + q[1]) * c)
+        },
+    );
+    let area = area / (X::one() + X::one());
+    if area.is_zero() {
+        return (area, polygon[0]);
+    }
+    let six_area = area * X::from(6.0).unwrap();
+    (area, [cx / six_area, cy / six_area])
+}
+
+/// Return the indices sorted by increasing abscissa, then ordinate.
+fn sorted<X: Scalar>(points: &[[X; 2]], indices: &[usize]) -> Vec<usize> {
+    let mut order = indices.to_vec();
+    order.sort_by(|&i, &j| {
+        points[i][0]
+            .partial_cmp(&points[j][0])
+            .unwrap()
+            .then(points[i][1].partial_cmp(&points[j][1]).unwrap())
+    });
+    order
+}
+
+/// Compute the Delaunay triangulation of the points of given indices, as counter-clockwise
+/// triangles; `None` is returned if the points are all collinear.
+///
+/// Points are first triangulated by sweeping them in lexicographic order, connecting each one
+/// to the edges of the convex hull it sees. Edges are then flipped until all of them are locally
+/// Delaunay, i.e. until no triangle has the opposite vertex of a neighbour in its circumcircle.
+fn delaunay<X: Scalar>(points: &[[X; 2]], indices: &[usize]) -> Option<Vec<[usize; 3]>> {
+    let order = sorted(points, indices);
+    let p = |i: usize| points[i];
+    // the first triangle is formed using the first point that is not collinear with the others
+    let first =
+        (2..order.len()).find(|&k| !cross(p(order[0]), p(order[1]), p(order[k])).is_zero())?;
+    let apex = order[first];
+    let mut triangles = Vec::with_capacity(2 * order.len());
+    let mut hull = Vec::with_capacity(order.len());
+    if cross(p(order[0]), p(order[1]), p(apex)) > X::zero() {
+        triangles.extend(order[..first].windows(2).map(|w| [w[0], w[1], apex]));
+        hull.extend_from_slice(&order[..=first]);
+    } else {
+        triangles.extend(order[..first].windows(2).map(|w| [w[1], w[0], apex]));
+        hull.push(order[0]);
+        hull.extend(order[1..=first].iter().rev());
+    }
+
+    for &new in &order[first + 1..] {
+        let n = hull.len();
+        let visible: Vec<bool> = (0..n)
+            .map(|i| cross(p(hull[i]), p(hull[(i + 1) % n]), p(new)) < X::zero())
+            .collect();
+        // visible edges form a single chain; find its start
+        let start = (0..n).find(|&i| visible[i] && !visible[(i + n - 1) % n])?;
+        let length = (0..n).take_while(|k| visible[(start + k) % n]).count();
+        for k in 0..length {
+            let (a, b) = (hull[(start + k) % n], hull[(start + k + 1) % n]);
+            triangles.push([a, new, b]);
+        }
+        hull = std::iter::once(hull[start])
+            .chain(std::iter::once(new))
+            .chain((length..n).map(|k| hull[(start + k) % n]))
+            .collect();
+    }
+
+    flip_edges(points, &mut triangles);
+    Some(triangles)
+}
+
+/// Flip edges of the triangulation until all of them are locally Delaunay.
+fn flip_edges<X: Scalar>(points: &[[X; 2]], triangles: &mut [[usize; 3]]) {
+    // each directed edge belongs to at most one counter-clockwise triangle
+    let mut owners: HashMap<(usize, usize), usize> = HashMap::new();
+    for (t, tri) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            owners.insert((tri[k], tri[(k + 1) % 3]), t);
+        }
+    }
+    let third =
+        |tri: [usize; 3], a: usize, b: usize| *tri.iter().find(|&&v| v != a && v != b).unwrap();
+
+    let mut stack: Vec<(usize, usize)> = owners.keys().copied().collect();
+    while let Some((a, b)) = stack.pop() {
+        let (Some(&t), Some(&u)) = (owners.get(&(a, b)), owners.get(&(b, a))) else {
+            continue;
+        };
+        let (c, d) = (third(triangles[t], a, b), third(triangles[u], a, b));
+        if !in_circle([points[a], points[b], points[c]], points[d]) {
+            continue;
+        }
+        // the quadrilateral (a, d, b, c) is convex; replace its diagonal (a, b) by (c, d)
+        owners.remove(&(a, b));
+        owners.remove(&(b, a));
+        triangles[t] = [a, d, c];
+        triangles[u] = [d, b, c];
+        for (edge, owner) in [
+            ((a, d), t),
+            ((d, c), t),
+            ((c, a), t),
+            ((d, b), u),
+            ((b, c), u),
+            ((c, d), u),
+        ] {
+            owners.insert(edge, owner);
+        }
+        stack.extend([(a, d), (d, b), (b, c), (c, a)]);
+    }
+}
+
+/// Return `true` if the point lies strictly inside the circumcircle of the counter-clockwise
+/// triangle, beyond rounding errors.
+fn in_circle<X: Scalar>(triangle: [[X; 2]; 3], point: [X; 2]) -> bool {
+    let rows = triangle.map(|vertex| {
+        let (u, v) = (vertex[0] - point[0], vertex[1] - point[1]);
+        (u, v, u * u + v * v)
+    });
+    let [(ax, ay, a2), (bx, by, b2), (cx, cy, c2)] = rows;
+    let det = a2 * (bx * cy - cx * by) - b2 * (ax * cy - cx * ay) + c2 * (ax * by - bx * ay);
+    let bound = a2 * (bx * cy).abs().max((cx * by).abs())
+        + b2 * (ax * cy).abs().max((cx * ay).abs())
+        + c2 * (ax * by).abs().max((bx * ay).abs());
+    det > X::from(16.0).unwrap() * X::epsilon() * bound
+}
+
+/// Clip a counter-clockwise polygon by a counter-clockwise triangle, using the
+/// Sutherland-Hodgman algorithm.
+///
+/// The polygon may be non-convex; the result may then contain degenerate edges, which do not
+/// change its area nor its centroid.
+fn clip_by_triangle<X: Scalar>(polygon: &[[X; 2]], triangle: [[X; 2]; 3]) -> Vec<[X; 2]> {
+    // ensure the triangle is counter-clockwise
+    let triangle = if cross(triangle[0], triangle[1], triangle[2]) < X::zero() {
+        [triangle[0], triangle[2], triangle[1]]
+    } else {
+        triangle
+    };
+    let mut output = polygon.to_vec();
+    for k in 0..3 {
+        let (e0, e1) = (triangle[k], triangle[(k + 1) % 3]);
+        let input = std::mem::take(&mut output);
+        for (i, &current) in input.iter().enumerate() {
+            let previous = input[(i + input.len() - 1) % input.len()];
+            let (side_c, side_p) = (cross(e0, e1, current), cross(e0, e1, previous));
+            if side_c >= X::zero() {
+                if side_p < X::zero() {
+                    output.push(intersection(previous, current, side_p, side_c));
+                }
+                output.push(current);
+            } else if side_p >= X::zero() {
+                output.push(intersection(previous, current, side_p, side_c));
+            }
+        }
+        if output.is_empty() {
+            break;
+        }
+    }
+    output
+}
+
+/// Return the intersection of the segment `[p, q]` with a line, given the signed distances
+/// (up to a common factor) of `p` & `q` to the line.
+fn intersection<X: Scalar>(p: [X; 2], q: [X; 2], side_p: X, side_q: X) -> [X; 2] {
+    let t = side_p / (side_p - side_q);
+    [p[0] + t * (q[0] - p[0]), p[1] + t * (q[1] - p[1])]
+}
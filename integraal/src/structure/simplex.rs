@@ -0,0 +1,229 @@
+//! simplex & polygon integration code
+
+// ------ IMPORTS
+
+use super::linalg;
+use crate::Scalar;
+
+// ------ CONTENT
+
+/// Quadrature rule over a simplex: barycentric coordinates of the points, and weights summing
+/// to one.
+pub(crate) type SimplexRule = Vec<(Vec<f64>, f64)>;
+
+/// Orbit of points of a triangle rule, invariant under permutations of barycentric coordinates.
+enum Orbit {
+    /// Centroid.
+    Centroid(f64),
+    /// Points `(1 - 2b, b, b)`, and their 3 permutations.
+    Two(f64, f64),
+    /// Points `(a, b, 1 - a - b)`, and their 6 permutations.
+    Three(f64, f64, f64),
+}
+
+/// Return Dunavant's rule of the given degree, between 1 and 8.
+pub(crate) fn dunavant(degree: usize) -> SimplexRule {
+    use Orbit::{Centroid, Three, Two};
+    let orbits: &[Orbit] = match degree {
+        1 => &[Centroid(1.0)],
+        2 => &[Two(1.0 / 6.0, 1.0 / 3.0)],
+        3 => &[Centroid(-0.5625), Two(0.2, 25.0 / 48.0)],
+        4 => &[
+            Two(0.445_948_490_915_965, 0.223_381_589_678_011),
+            Two(0.091_576_213_509_771, 0.109_951_743_655_322),
+        ],
+        5 => &[
+            Centroid(0.225),
+            Two(0.470_142_064_105_115, 0.132_394_152_788_506),
+            Two(0.101_286_507_323_456, 0.125_939_180_544_827),
+        ],
+        6 => &[
+            Two(0.249_286_745_170_910, 0.116_786_275_726_379),
+            Two(0.063_089_014_491_502, 0.050_844_906_370_207),
+            Three(
+                0.053_145_049_844_817,
+                0.310_352_451_033_784,
+                0.082_851_075_618_374,
+            ),
+        ],
+        7 => &[
+            Centroid(-0.149_570_044_467_682),
+            Two(0.260_345_966_079_040, 0.175_615_257_433_208),
+            Two(0.065_130_102_902_216, 0.053_347_235_608_838),
+            Three(
+                0.048_690_315_425_316,
+                0.312_865_496_004_874,
+                0.077_113_760_890_257,
+            ),
+        ],
+        8 => &[
+            Centroid(0.144_315_607_677_787),
+            Two(0.459_292_588_292_723, 0.095_091_634_267_285),
+            Two(0.170_569_307_751_760, 0.103_217_370_534_718),
+            Two(0.050_547_228_317_031, 0.032_458_497_623_198),
+            Three(
+                0.008_394_777_409_958,
+                0.263_112_829_634_638,
+                0.027_230_314_174_435,
+            ),
+        ],
+        _ => unreachable!(),
+    };
+
+    let mut rule = Vec::new();
+    for orbit in orbits {
+        match *orbit {
+            Centroid(w) => rule.push((vec![1.0 / 3.0; 3], w)),
+            Two(b, w) => {
+                let a = 1.0 - 2.0 * b;
+                rule.extend([[a, b, b], [b, a, b], [b, b, a]].map(|p| (p.to_vec(), w)));
+            }
+            Three(a, b, w) => {
+                let c = 1.0 - a - b;
+                rule.extend(
+                    [
+                        [a, b, c],
+                        [a, c, b],
+                        [b, a, c],
+                        [b, c, a],
+                        [c, a, b],
+                        [c, b, a],
+                    ]
+                    .map(|p| (p.to_vec(), w)),
+                );
+            }
+        }
+    }
+    rule
+}
+
+/// Return the Grundmann-Möller rule of the smallest index exact up to the given degree, over a
+/// simplex of dimension `dim`.
+///
+/// The rule of index `s` is a combination of `s + 1` rules whose points lie on regular lattices
+/// of the simplex.
+pub(crate) fn grundmann_moller(dim: usize, degree: usize) -> SimplexRule {
+    let s = degree / 2;
+    let d = 2 * s + 1;
+    let factorial = |k: usize| (1..=k).fold(1.0, |acc, i| acc * i as f64);
+
+    let mut rule = Vec::new();
+    for i in 0..=s {
+        let den = (d + dim - 2 * i) as f64;
+        let sign = if i.is_multiple_of(2) { 1.0 } else { -1.0 };
+        // weights are scaled by dim! so that they sum to one
+        let weight = sign * den.powi(i32::try_from(d).unwrap()) * factorial(dim)
+            / (4.0_f64.powi(i32::try_from(s).unwrap()) * factorial(i) * factorial(d + dim - i));
+        for beta in compositions(s - i, dim + 1) {
+            let point = beta.iter().map(|b| (2 * b + 1) as f64 / den).collect();
+            rule.push((point, weight));
+        }
+    }
+    rule
+}
+
+/// Return all ways to write `total` as an ordered sum of `n_parts` non-negative integers.
+fn compositions(total: usize, n_parts: usize) -> Vec<Vec<usize>> {
+    if n_parts == 1 {
+        return vec![vec![total]];
+    }
+    (0..=total)
+        .flat_map(|first| {
+            compositions(total - first, n_parts - 1)
+                .into_iter()
+                .map(move |mut rest| {
+                    rest.insert(0, first);
+                    rest
+                })
+        })
+        .collect()
+}
+
+/// Return the volume of a simplex, described by its vertices.
+pub(crate) fn volume<X: Scalar>(vertices: &[Vec<X>]) -> X {
+    let dim = vertices.len() - 1;
+    let matrix = vertices[1..]
+        .iter()
+        .flat_map(|v| v.iter().zip(&vertices[0]).map(|(x, x0)| *x - *x0))
+        .collect();
+    let factorial = (1..=dim).fold(X::one(), |acc, i| acc * X::from(i).unwrap());
+    linalg::determinant(matrix, dim).abs() / factorial
+}
+
+/// Return the point of given barycentric coordinates in a simplex.
+pub(crate) fn point<X: Scalar>(vertices: &[Vec<X>], barycentric: &[f64]) -> Vec<X> {
+    (0..vertices[0].len())
+        .map(|k| {
+            vertices
+                .iter()
+                .zip(barycentric)
+                .fold(X::zero(), |acc, (v, l)| acc + v[k] * X::from(*l).unwrap())
+        })
+        .collect()
+}
+
+/// Triangulate a simple polygon using ear clipping, returning triangles as triples of vertex
+/// indices; `None` is returned if the polygon is not simple.
+///
+/// An ear is a convex vertex whose triangle, formed with its neighbours, contains no other
+/// vertex; clipping it leaves a simple polygon with one vertex less.
+pub(crate) fn triangulate<X: Scalar>(polygon: &[[X; 2]]) -> Option<Vec<[usize; 3]>> {
+    let cross = |o: [X; 2], a: [X; 2], b: [X; 2]| {
+        (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+    };
+    let area = (0..polygon.len()).fold(X::zero(), |acc, i| {
+        let (p, q) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+        acc + p[0] * q[1] - p[1] * q[0]
+    });
+    // orient the polygon counter-clockwise
+    let mut remaining: Vec<usize> = if area > X::zero() {
+        (0..polygon.len()).collect()
+    } else {
+        (0..polygon.len()).rev().collect()
+    };
+
+    let mut triangles = Vec::with_capacity(polygon.len() - 2);
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let corner = |i: usize| {
+            (
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            )
+        };
+        // vertices lying between their neighbours do not change the polygon
+        if let Some(i) = (0..n).find(|&i| {
+            let (a, b, c) = corner(i);
+            cross(polygon[a], polygon[b], polygon[c]).is_zero()
+        }) {
+            remaining.remove(i);
+            continue;
+        }
+        let ear = (0..n).find(|&i| {
+            let (a, b, c) = corner(i);
+            let (pa, pb, pc) = (polygon[a], polygon[b], polygon[c]);
+            cross(pa, pb, pc) > X::zero()
+                && remaining.iter().all(|&j| {
+                    j == a
+                        || j == b
+                        || j == c
+                        || cross(pa, pb, polygon[j]) < X::zero()
+                        || cross(pb, pc, polygon[j]) < X::zero()
+                        || cross(pc, pa, polygon[j]) < X::zero()
+                })
+        })?;
+        triangles.push([
+            remaining[(ear + n - 1) % n],
+            remaining[ear],
+            remaining[(ear + 1) % n],
+        ]);
+        remaining.remove(ear);
+    }
+    let [a, b, c] = [remaining[0], remaining[1], remaining[2]];
+    if cross(polygon[a], polygon[b], polygon[c]) <= X::zero() {
+        return None;
+    }
+    triangles.push([a, b, c]);
+    Some(triangles)
+}
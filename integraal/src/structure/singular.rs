@@ -0,0 +1,151 @@
+//! singular integral code
+
+// ------ IMPORTS
+
+use super::{nodes, summation::SumWith};
+use crate::{LogWeight, Scalar, Summation};
+
+// ------ CONTENT
+
+/// Compute the Cauchy principal value of the integral of `f(x) / (x - pole)` over
+/// `[start, end]`.
+///
+/// Over `[pole - d, pole + d]`, `d` being the distance from the pole to the closest limit, the
+/// integral is equal to the one of `(f(pole + t) - f(pole - t)) / t` over `[0, d]`, which is
+/// regular. The rest of the domain is split into segments whose lengths double away from the
+/// pole, so that `1 / (x - pole)` is resolved at all scales.
+pub(crate) fn cauchy<X: Scalar>(
+    f: impl Fn(X) -> X,
+    (start, end): (X, X),
+    pole: X,
+    n_nodes: usize,
+    summation: Summation,
+) -> X {
+    let rule: Vec<(X, X)> = (0..n_nodes)
+        .map(|idx| nodes::gauss_legendre(n_nodes, idx))
+        .collect();
+    let gauss = |lo: X, hi: X, g: &dyn Fn(X) -> X| {
+        let half_length = (hi - lo) * X::from(0.5).unwrap();
+        half_length
+            * rule
+                .iter()
+                .map(|&(t, w)| w * g(nodes::map_reference(lo, hi, t)))
+                .sum_with(summation)
+    };
+
+    let d = (pole - start).min(end - pole);
+    let mut parts = vec![gauss(X::zero(), d, &|t| (f(pole + t) - f(pole - t)) / t)];
+    let g = |x: X| f(x) / (x - pole);
+    // segments have the same length as their distance to the pole; at most one loop runs
+    let (mut inner, mut width) = (pole - d, d);
+    while inner > start {
+        let outer = (inner - width).max(start);
+        parts.push(gauss(outer, inner, &g));
+        (inner, width) = (outer, width + width);
+    }
+    let (mut inner, mut width) = (pole + d, d);
+    while inner < end {
+        let outer = (inner + width).min(end);
+        parts.push(gauss(inner, outer, &g));
+        (inner, width) = (outer, width + width);
+    }
+    parts.into_iter().sum_with(summation)
+}
+
+/// Compute the integral of `f(x) * (x - a)^alpha * (b - x)^beta * v(x)` over `[a, b]`, `v`
+/// being described by `log`.
+///
+/// The domain is split in two halves, so that each one contains a single singular endpoint.
+pub(crate) fn algebraic_logarithmic<X: Scalar>(
+    f: impl Fn(X) -> X,
+    (a, b): (X, X),
+    (alpha, beta): (X, X),
+    log: LogWeight,
+    n_levels: usize,
+    summation: Summation,
+) -> X {
+    let length = b - a;
+    let half_length = length * X::from(0.5).unwrap();
+    let weight = |ln_start: X, ln_end: X| match log {
+        LogWeight::None => X::one(),
+        LogWeight::Start => ln_start,
+        LogWeight::End => ln_end,
+        LogWeight::Both => ln_start * ln_end,
+    };
+
+    // integrands are expressed using the distance to the singular endpoint, and its logarithm
+    let lower = endpoint_integral(
+        |d: X, ln_d: X| {
+            let ln_e = (length - d).ln();
+            f(a + d) * (beta * ln_e).exp() * weight(ln_d, ln_e)
+        },
+        alpha,
+        half_length,
+        n_levels,
+        summation,
+    );
+    let upper = endpoint_integral(
+        |d: X, ln_d: X| {
+            let ln_e = (length - d).ln();
+            f(b - d) * (alpha * ln_e).exp() * weight(ln_e, ln_d)
+        },
+        beta,
+        half_length,
+        n_levels,
+        summation,
+    );
+    lower + upper
+}
+
+/// Compute the integral of `d^gamma * g(d, ln(d))` over `[0, length]`, using the tanh-sinh rule.
+///
+/// If `gamma` is negative, the change of variable `d = u^p`, where `p = 1 / (1 + gamma)`, turns
+/// the integral into the one of `p * g(u^p, p * ln(u))`, which is bounded.
+fn endpoint_integral<X: Scalar>(
+    g: impl Fn(X, X) -> X,
+    gamma: X,
+    length: X,
+    n_levels: usize,
+    summation: Summation,
+) -> X {
+    let (p, gamma) = if gamma < X::zero() {
+        ((X::one() + gamma).recip(), X::zero())
+    } else {
+        (X::one(), gamma)
+    };
+    let ln_end = length.ln() / p;
+    let integrand = |ln_u: X| {
+        let ln_d = p * ln_u;
+        p * (gamma * ln_d).exp() * g(ln_d.exp(), ln_d)
+    };
+
+    // abscissae are computed in logarithmic form, so that they do not underflow to zero
+    let (half, half_pi, two) = (
+        X::from(0.5).unwrap(),
+        X::from(std::f64::consts::FRAC_PI_2).unwrap(),
+        X::from(2.0).unwrap(),
+    );
+    let step = half.powi(i32::try_from(n_levels).unwrap());
+    // terms decay double-exponentially, and are negligible beyond t = 4
+    let n_step = 4 << n_levels;
+    std::iter::once(X::zero())
+        .chain((1..=n_step).flat_map(|j| {
+            let t = step * X::from(j).unwrap();
+            [-t, t]
+        }))
+        .map(|t| {
+            let s = half_pi * t.sinh();
+            // u = end / (1 + e^{-2s}); du/dt = end * (pi/2) * cosh(t) / (2 * cosh^2(s))
+            let e = (-two * s.abs()).exp();
+            let ln_u = if s < X::zero() {
+                ln_end + two * s - e.ln_1p()
+            } else {
+                ln_end - e.ln_1p()
+            };
+            let jacobian = half_pi * t.cosh() * two * e / ((X::one() + e) * (X::one() + e));
+            jacobian * integrand(ln_u)
+        })
+        .sum_with(summation)
+        * step
+        * ln_end.exp()
+}
@@ -0,0 +1,402 @@
+//! sparse grid integration code
+
+// ------ IMPORTS
+
+use super::{
+    linalg,
+    nodes::{self, map_reference},
+    summation::SumWith,
+};
+use crate::{Evaluation, IntegraalError, NestedRule, NonFinitePolicy, Scalar, Summation};
+use std::collections::{HashMap, HashSet};
+
+// ------ CONTENT
+
+/// Maximum level of the nested Gauss-Patterson rules.
+const GAUSS_PATTERSON_MAX_LEVEL: usize = 5;
+
+/// Maximum level of the other nested rules.
+const MAX_LEVEL: usize = 12;
+
+/// Check that the level is available for the nested rule.
+pub(crate) fn validate(rule: NestedRule, level: usize) -> Result<(), IntegraalError> {
+    let max_level = match rule {
+        NestedRule::GaussPatterson => GAUSS_PATTERSON_MAX_LEVEL,
+        NestedRule::ClenshawCurtis | NestedRule::Trapezoid => MAX_LEVEL,
+    };
+    if level == 0 || level > max_level {
+        return Err(IntegraalError::BadParameters(format!(
+            "Smolyak's rules with {rule:?} rules are available from level 1 to {max_level} (level: {level})"
+        )));
+    }
+    Ok(())
+}
+
+/// Nested rules over `[-1, 1]`, up to a given level.
+///
+/// Nodes are listed in order of appearance, so that the nodes of the rule of level `l` are the
+/// first ones. Each level stores the weights of the difference between its rule and the one of
+/// the previous level, which is zero at the nodes of the latter if their weights are unchanged.
+struct NestedNodes {
+    nodes: Vec<f64>,
+    differences: Vec<Vec<f64>>,
+}
+
+impl NestedNodes {
+    fn new(rule: NestedRule, max_level: usize) -> Self {
+        let mut nodes: Vec<f64> = Vec::new();
+        let mut differences: Vec<Vec<f64>> = Vec::with_capacity(max_level + 1);
+        let mut previous_weights: Vec<f64> = Vec::new();
+        let mut previous_nodes: Vec<f64> = Vec::new();
+        for level in 0..=max_level {
+            let (level_nodes, level_weights) = match rule {
+                NestedRule::ClenshawCurtis => clenshaw_curtis(level),
+                NestedRule::GaussPatterson => gauss_patterson(&previous_nodes),
+                NestedRule::Trapezoid => trapezoid(level),
+            };
+            let mut weights = vec![0.0; level_nodes.len()];
+            for (x, w) in level_nodes.iter().zip(&level_weights) {
+                // nodes shared with previous levels may differ by rounding errors
+                let id = nodes
+                    .iter()
+                    .position(|node| (node - x).abs() <= 1e-12)
+                    .unwrap_or_else(|| {
+                        nodes.push(*x);
+                        nodes.len() - 1
+                    });
+                weights[id] = *w;
+            }
+            debug_assert_eq!(nodes.len(), level_nodes.len(), "rules should be nested");
+            let mut difference = weights.clone();
+            for (d, w) in difference.iter_mut().zip(&previous_weights) {
+                *d -= w;
+            }
+            differences.push(difference);
+            previous_weights = weights;
+            previous_nodes = level_nodes;
+        }
+        Self { nodes, differences }
+    }
+}
+
+/// Return the Clenshaw-Curtis rule of given level, which has 1 node if the level is zero, and
+/// `2^level + 1` nodes otherwise.
+fn clenshaw_curtis(level: usize) -> (Vec<f64>, Vec<f64>) {
+    if level == 0 {
+        return (vec![0.0], vec![2.0]);
+    }
+    let n: usize = 1 << level;
+    let nodes = (0..=n)
+        .map(|j| (std::f64::consts::PI * j as f64 / n as f64).cos())
+        .collect();
+    let weights = (0..=n)
+        .map(|j| {
+            let c = if j == 0 || j == n { 1.0 } else { 2.0 };
+            let series = (1..=n / 2).fold(0.0, |acc, k| {
+                let b = if 2 * k == n { 1.0 } else { 2.0 };
+                let theta = std::f64::consts::PI * (2 * k * j) as f64 / n as f64;
+                acc + b / (4.0 * (k * k) as f64 - 1.0) * theta.cos()
+            });
+            c / n as f64 * (1.0 - series)
+        })
+        .collect();
+    (nodes, weights)
+}
+
+/// Return the trapezoid rule of given level, which has 1 node (the midpoint rule) if the level
+/// is zero, and `2^level + 1` nodes otherwise.
+fn trapezoid(level: usize) -> (Vec<f64>, Vec<f64>) {
+    if level == 0 {
+        return (vec![0.0], vec![2.0]);
+    }
+    let n: usize = 1 << level;
+    let step = 2.0 / n as f64;
+    let nodes = (0..=n).map(|j| 2.0 * j as f64 / n as f64 - 1.0).collect();
+    let weights = (0..=n)
+        .map(|j| if j == 0 || j == n { step / 2.0 } else { step })
+        .collect();
+    (nodes, weights)
+}
+
+/// Return the Gauss-Patterson rule extending the given one, i.e. the rule of highest degree
+/// using its nodes & as many new ones plus one; the rule of level zero is the midpoint rule.
+///
+/// New nodes are the roots of the polynomial orthogonal to all polynomials of lower degree,
+/// with respect to the weight given by the product of `x - x_i` over the previous nodes. They
+/// interlace with the previous nodes, which are kept as-is.
+fn gauss_patterson(previous: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    if previous.is_empty() {
+        return (vec![0.0], vec![2.0]);
+    }
+    let m = previous.len();
+    // Gauss-Legendre rule exact for the products involved, of degree at most 3m + 2
+    let quadrature: Vec<(f64, f64)> = (0..2 * m + 2)
+        .map(|i| nodes::gauss_legendre(2 * m + 2, i))
+        .collect();
+    let weight = |x: f64| previous.iter().fold(1.0, |acc, xi| acc * (x - xi));
+    let legendre_all = |x: f64| {
+        let mut p = vec![1.0, x];
+        for k in 2..=m + 1 {
+            let k_f = k as f64;
+            p.push(((2.0 * k_f - 1.0) * x * p[k - 1] - (k_f - 1.0) * p[k - 2]) / k_f);
+        }
+        p
+    };
+
+    // the polynomial is P_{m+1} + sum of c_i P_i, orthogonal to P_0, ..., P_m
+    let mut a = vec![0.0; (m + 1) * (m + 1)];
+    let mut b = vec![0.0; m + 1];
+    for (x, w) in &quadrature {
+        let (p, ww) = (legendre_all(*x), w * weight(*x));
+        for k in 0..=m {
+            for i in 0..=m {
+                a[k * (m + 1) + i] += ww * p[i] * p[k];
+            }
+            b[k] -= ww * p[m + 1] * p[k];
+        }
+    }
+    let coefficients = linalg::solve(a, b).expect("Gauss-Patterson extension should exist");
+    let polynomial = |x: f64| {
+        let p = legendre_all(x);
+        p[m + 1]
+            + coefficients
+                .iter()
+                .zip(&p)
+                .fold(0.0, |acc, (c, pi)| acc + c * pi)
+    };
+
+    // one root lies between each pair of consecutive previous nodes, and near each limit
+    let mut sorted = previous.to_vec();
+    sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    let bounds: Vec<f64> = std::iter::once(-1.0)
+        .chain(sorted)
+        .chain(std::iter::once(1.0))
+        .collect();
+    let mut all = previous.to_vec();
+    for w in bounds.windows(2) {
+        let (mut lo, mut hi) = (w[0], w[1]);
+        let positive_lo = polynomial(lo) > 0.0;
+        for _ in 0..100 {
+            let mid = 0.5 * (lo + hi);
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if (polynomial(mid) > 0.0) == positive_lo {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        all.push(0.5 * (lo + hi));
+    }
+
+    // weights are the integrals of the Lagrange polynomials, in barycentric form
+    let lambda: Vec<f64> = all
+        .iter()
+        .enumerate()
+        .map(|(j, xj)| {
+            1.0 / all
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| *k != j)
+                .fold(1.0, |acc, (_, xk)| acc * (xj - xk))
+        })
+        .collect();
+    let mut weights = vec![0.0; all.len()];
+    for (x, w) in &quadrature {
+        let terms: Vec<f64> = all
+            .iter()
+            .zip(&lambda)
+            .map(|(xj, l)| l / (x - xj))
+            .collect();
+        let total: f64 = terms.iter().sum();
+        for (weight, term) in weights.iter_mut().zip(&terms) {
+            *weight += w * term / total;
+        }
+    }
+    (all, weights)
+}
+
+/// Return all multi-indices of given dimension whose entries sum to `total`.
+fn indices(dim: usize, total: usize) -> Vec<Vec<usize>> {
+    if dim == 1 {
+        return vec![vec![total]];
+    }
+    (0..=total)
+        .flat_map(|first| {
+            indices(dim - 1, total - first)
+                .into_iter()
+                .map(move |mut rest| {
+                    rest.insert(0, first);
+                    rest
+                })
+        })
+        .collect()
+}
+
+/// Sparse grid over a hyperrectangle, caching function values at its points.
+struct SparseGrid<'a, X: Scalar> {
+    rules: NestedNodes,
+    bounds: &'a [(X, X)],
+    function: &'a dyn Fn(&[X]) -> X,
+    policy: NonFinitePolicy,
+    summation: Summation,
+    values: HashMap<Vec<usize>, X>,
+}
+
+impl<X: Scalar> SparseGrid<'_, X> {
+    /// Return the value of the function at the point of given node indices.
+    fn value(&mut self, ids: &[usize]) -> Result<X, IntegraalError> {
+        if let Some(value) = self.values.get(ids) {
+            return Ok(*value);
+        }
+        let point: Vec<X> = ids
+            .iter()
+            .zip(self.bounds)
+            .map(|(id, (start, end))| {
+                map_reference(*start, *end, X::from(self.rules.nodes[*id]).unwrap())
+            })
+            .collect();
+        let mut value = (self.function)(&point);
+        if !value.is_finite() {
+            if self.policy == NonFinitePolicy::Error {
+                return Err(IntegraalError::NonFinite {
+                    index: self.values.len(),
+                    abscissa: point[0].to_f64().unwrap(),
+                });
+            }
+            value = X::zero();
+        }
+        self.values.insert(ids.to_vec(), value);
+        Ok(value)
+    }
+
+    /// Compute the contribution of a multi-index, i.e. the tensor product of the differences
+    /// between the rules of its levels & the ones of the previous levels.
+    fn difference(&mut self, index: &[usize]) -> Result<X, IntegraalError> {
+        let sizes: Vec<usize> = index
+            .iter()
+            .map(|level| self.rules.differences[*level].len())
+            .collect();
+        let jacobian = self.bounds.iter().fold(X::one(), |acc, (start, end)| {
+            acc * (*end - *start) * X::from(0.5).unwrap()
+        });
+        let mut ids = vec![0; index.len()];
+        let mut terms = Vec::new();
+        'points: loop {
+            let weight = ids.iter().zip(index).fold(1.0, |acc, (id, level)| {
+                acc * self.rules.differences[*level][*id]
+            });
+            if weight != 0.0 {
+                terms.push(X::from(weight).unwrap() * self.value(&ids)?);
+            }
+            // next point, in lexicographic order
+            for axis in (0..ids.len()).rev() {
+                ids[axis] += 1;
+                if ids[axis] < sizes[axis] {
+                    continue 'points;
+                }
+                ids[axis] = 0;
+            }
+            break;
+        }
+        Ok(jacobian * terms.into_iter().sum_with(self.summation))
+    }
+}
+
+/// Compute the integral of a function over a hyperrectangle using Smolyak's sparse grid rule,
+/// along with an error indicator.
+///
+/// Without tolerance, all multi-indices whose levels sum to at most `level` are used, and the
+/// indicator is the difference with the rule of the previous level. With a tolerance, indices
+/// are added one at a time, refining the axes of the largest contributions first; the indicator
+/// is the sum of the contributions of the indices that were not refined yet.
+pub(crate) fn smolyak<X: Scalar>(
+    bounds: &[(X, X)],
+    function: &dyn Fn(&[X]) -> X,
+    (rule, level, tolerance): (NestedRule, usize, Option<f64>),
+    policy: NonFinitePolicy,
+    summation: Summation,
+) -> Result<Evaluation<X>, IntegraalError> {
+    let dim = bounds.len();
+    let mut grid = SparseGrid {
+        rules: NestedNodes::new(rule, level),
+        bounds,
+        function,
+        policy,
+        summation,
+        values: HashMap::new(),
+    };
+    let evaluation = |value, error, evaluations| Evaluation {
+        value,
+        breakpoints: Vec::new(),
+        error: Some(error),
+        evaluations: Some(evaluations),
+    };
+
+    let Some(tolerance) = tolerance else {
+        let mut contributions = Vec::new();
+        let mut last = Vec::new();
+        for total in 0..=level {
+            last = indices(dim, total)
+                .iter()
+                .map(|index| grid.difference(index))
+                .collect::<Result<Vec<X>, _>>()?;
+            contributions.extend_from_slice(&last);
+        }
+        let error = last.into_iter().sum_with(summation).abs();
+        return Ok(evaluation(
+            contributions.into_iter().sum_with(summation),
+            error,
+            grid.values.len(),
+        ));
+    };
+
+    // dimension-adaptive refinement
+    let tolerance = X::from(tolerance).unwrap();
+    let mut old: HashSet<Vec<usize>> = HashSet::new();
+    let mut active: Vec<(Vec<usize>, X)> = vec![(vec![0; dim], grid.difference(&vec![0; dim])?)];
+    let mut contributions = vec![active[0].1];
+    loop {
+        let error = active
+            .iter()
+            .map(|(_, contribution)| contribution.abs())
+            .sum_with(summation);
+        if error <= tolerance {
+            return Ok(evaluation(
+                contributions.into_iter().sum_with(summation),
+                error,
+                grid.values.len(),
+            ));
+        }
+        let Some(position) = active
+            .iter()
+            .enumerate()
+            .filter(|(_, (index, _))| index.iter().sum::<usize>() < level)
+            .max_by(|(_, (_, a)), (_, (_, b))| a.abs().partial_cmp(&b.abs()).unwrap())
+            .map(|(position, _)| position)
+        else {
+            return Err(IntegraalError::NotConverged {
+                achieved_error: error.to_f64().unwrap(),
+                evaluations: grid.values.len(),
+            });
+        };
+        let (index, _) = active.swap_remove(position);
+        old.insert(index.clone());
+        for axis in 0..dim {
+            let mut next = index.clone();
+            next[axis] += 1;
+            // the index is admissible if all of its backward neighbours were refined
+            let admissible = (0..dim).filter(|j| next[*j] > 0).all(|j| {
+                let mut previous = next.clone();
+                previous[j] -= 1;
+                old.contains(&previous)
+            });
+            if admissible {
+                let contribution = grid.difference(&next)?;
+                contributions.push(contribution);
+                active.push((next, contribution));
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! summation algorithms
+
+// ------ IMPORTS
+
+use crate::{Scalar, Summation};
+
+// ------ CONTENT
+
+/// Summation of an iterator using a given strategy.
+pub(crate) trait SumWith<X: Scalar>: Iterator<Item = X> + Sized {
+    /// Sum all items of the iterator using the specified strategy.
+    fn sum_with(self, strategy: Summation) -> X {
+        match strategy {
+            Summation::Naive => self.sum(),
+            Summation::Pairwise => pairwise(self),
+            Summation::KahanBabuskaNeumaier => neumaier(self),
+            Summation::Exact => exact(self),
+        }
+    }
+}
+
+impl<X: Scalar, I: Iterator<Item = X>> SumWith<X> for I {}
+
+/// Pairwise summation.
+///
+/// Items are combined as the leaves of a binary tree; partial sums are kept on a stack, so that
+/// the memory footprint is logarithmic in the number of items.
+fn pairwise<X: Scalar>(iter: impl Iterator<Item = X>) -> X {
+    // (partial sum, tree level)
+    let mut stack: Vec<(X, u32)> = Vec::new();
+    for x in iter {
+        let (mut sum, mut level) = (x, 0);
+        while let Some((top, top_level)) = stack.last().copied() {
+            if top_level != level {
+                break;
+            }
+            stack.pop();
+            sum = top + sum;
+            level += 1;
+        }
+        stack.push((sum, level));
+    }
+    // higher levels are at the bottom of the stack; start from the smallest partial sums
+    stack
+        .into_iter()
+        .rev()
+        .fold(X::zero(), |acc, (sum, _)| acc + sum)
+}
+
+/// Kahan-Babuška-Neumaier compensated summation.
+fn neumaier<X: Scalar>(iter: impl Iterator<Item = X>) -> X {
+    let (mut sum, mut compensation) = (X::zero(), X::zero());
+    for x in iter {
+        let t = sum + x;
+        if sum.abs() >= x.abs() {
+            compensation = compensation + ((sum - t) + x);
+        } else {
+            compensation = compensation + ((x - t) + sum);
+        }
+        sum = t;
+    }
+    sum + compensation
+}
+
+/// Exact summation, correctly rounded.
+///
+/// This uses Shewchuk's algorithm: the running sum is stored as a list of non-overlapping partial
+/// sums, updated using error-free transformations. Non-finite values are summed naively.
+fn exact<X: Scalar>(iter: impl Iterator<Item = X>) -> X {
+    let mut partials: Vec<X> = Vec::new();
+    let mut special = X::zero();
+    for mut x in iter {
+        if !x.is_finite() {
+            special = special + x;
+            continue;
+        }
+        let mut i = 0;
+        for j in 0..partials.len() {
+            let mut y = partials[j];
+            if x.abs() < y.abs() {
+                std::mem::swap(&mut x, &mut y);
+            }
+            let hi = x + y;
+            let lo = y - (hi - x);
+            if !lo.is_zero() {
+                partials[i] = lo;
+                i += 1;
+            }
+            x = hi;
+        }
+        partials.truncate(i);
+        partials.push(x);
+    }
+    if !special.is_zero() {
+        return special;
+    }
+
+    // add partials from the largest, stopping once the result is inexact
+    let Some(mut hi) = partials.pop() else {
+        return X::zero();
+    };
+    let mut lo = X::zero();
+    while let Some(y) = partials.pop() {
+        let x = hi;
+        hi = x + y;
+        lo = y - (hi - x);
+        if !lo.is_zero() {
+            break;
+        }
+    }
+    // round half-even correction, if the remaining partials have the same sign as the error
+    if let Some(last) = partials.last() {
+        if lo.is_sign_negative() == last.is_sign_negative() && !lo.is_zero() && !last.is_zero() {
+            let y = lo + lo;
+            let x = hi + y;
+            if y == x - hi {
+                hi = x;
+            }
+        }
+    }
+    hi
+}
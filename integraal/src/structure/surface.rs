@@ -0,0 +1,121 @@
+//! surface integration code
+
+// ------ IMPORTS
+
+use super::validation;
+use crate::{
+    ComputeMethod, DomainDescriptor, FunctionDescriptor, IntegraalError, Scalar, SurfaceDescriptor,
+};
+
+// ------ CONTENT
+
+/// Check that the parameter domains of the surface fit the requirements of the method.
+pub(crate) fn validate<X: Scalar>(
+    surface: &SurfaceDescriptor<X>,
+    method: ComputeMethod,
+    allow_descending: bool,
+) -> Result<(), IntegraalError> {
+    for (name, domain) in [("u", &surface.u), ("v", &surface.v)] {
+        if let DomainDescriptor::Piecewise(_) = domain {
+            return Err(IntegraalError::BadParameters(format!(
+                "surface parameter domains cannot be piecewise (parameter: {name})"
+            )));
+        }
+        validation::validate(domain, method, allow_descending)?;
+    }
+    Ok(())
+}
+
+/// Check that the function can be integrated over the surface.
+pub(crate) fn check_function<X: Scalar>(
+    surface: &SurfaceDescriptor<X>,
+    function: &FunctionDescriptor<X>,
+) -> Result<(), IntegraalError> {
+    match function {
+        FunctionDescriptor::MultiClosure(_) => Ok(()),
+        FunctionDescriptor::Values(vals) => {
+            let n_sample = surface.u.n_sample() * surface.v.n_sample();
+            if vals.len() == n_sample {
+                Ok(())
+            } else {
+                Err(IntegraalError::LengthMismatch {
+                    domain: n_sample,
+                    function: vals.len(),
+                })
+            }
+        }
+        FunctionDescriptor::VectorField(_) => {
+            let dim = (surface.point)(surface.u.abscissa(0), surface.v.abscissa(0)).len();
+            if dim == 3 {
+                Ok(())
+            } else {
+                Err(IntegraalError::BadParameters(format!(
+                    "flux integrals require a surface in 3D (dimension: {dim})"
+                )))
+            }
+        }
+        _ => Err(IntegraalError::InconsistentParameters(String::from(
+            "surfaces require a function of several variables (`FunctionDescriptor::MultiClosure`), a vector field (`FunctionDescriptor::VectorField`) or values over the parameter grid",
+        ))),
+    }
+}
+
+/// Return the partial derivatives of the surface at `(u, v)`, using the user-provided closure if
+/// there is one, central finite differences otherwise.
+pub(crate) fn partials<X: Scalar>(surface: &SurfaceDescriptor<X>, u: X, v: X) -> (Vec<X>, Vec<X>) {
+    if let Some(partials) = &surface.partials {
+        return partials(u, v);
+    }
+    // the step is scaled so that truncation & rounding errors are balanced
+    let step = |x: X| X::epsilon().cbrt() * x.abs().max(X::one());
+    let difference = |before: Vec<X>, after: Vec<X>, h: X| -> Vec<X> {
+        after
+            .iter()
+            .zip(&before)
+            .map(|(a, b)| (*a - *b) / (h + h))
+            .collect()
+    };
+    let point = surface.point.as_ref();
+    let (hu, hv) = (step(u), step(v));
+    (
+        difference(point(u - hu, v), point(u + hu, v), hu),
+        difference(point(u, v - hv), point(u, v + hv), hv),
+    )
+}
+
+/// Return the area element of the surface given its partial derivatives, i.e. the square root of
+/// the Gram determinant of the derivatives; this is `|r_u x r_v|` in 3D.
+pub(crate) fn area_element<X: Scalar>(ru: &[X], rv: &[X]) -> X {
+    let dot = |a: &[X], b: &[X]| a.iter().zip(b).fold(X::zero(), |acc, (x, y)| acc + *x * *y);
+    (dot(ru, ru) * dot(rv, rv) - dot(ru, rv).powi(2))
+        .max(X::zero())
+        .sqrt()
+}
+
+/// Return the integrand of the surface integral of the function at the point of parameters
+/// `(u, v)`; this should not be used on values.
+pub(crate) fn element<X: Scalar>(
+    surface: &SurfaceDescriptor<X>,
+    function: &FunctionDescriptor<X>,
+    u: X,
+    v: X,
+) -> X {
+    let (ru, rv) = partials(surface, u, v);
+    let point = (surface.point)(u, v);
+    match function {
+        FunctionDescriptor::MultiClosure(closure) => closure(&point) * area_element(&ru, &rv),
+        FunctionDescriptor::VectorField(field) => {
+            let normal = [
+                ru[1] * rv[2] - ru[2] * rv[1],
+                ru[2] * rv[0] - ru[0] * rv[2],
+                ru[0] * rv[1] - ru[1] * rv[0],
+            ];
+            field(&point)
+                .iter()
+                .zip(normal)
+                .fold(X::zero(), |acc, (f, n)| acc + *f * n)
+        }
+        // rejected before computation
+        _ => unreachable!(),
+    }
+}
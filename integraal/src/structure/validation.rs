@@ -0,0 +1,479 @@
+//! parameter validation code
+
+// ------ IMPORTS
+
+use super::UniformGrid;
+use crate::{
+    ComputeMethod, DomainDescriptor, IntegraalError, NodeGenerator, Piece, Scalar, Spacing,
+};
+
+// ------ CONTENT
+
+/// Return the minimum number of samples required by a method.
+pub(crate) fn min_samples(method: ComputeMethod) -> usize {
+    match method {
+        ComputeMethod::RectangleLeft | ComputeMethod::RectangleRight | ComputeMethod::Trapezoid => {
+            2
+        }
+        ComputeMethod::Simpson => 3,
+        #[cfg(feature = "open")]
+        ComputeMethod::Midpoint | ComputeMethod::OpenNewtonCotes { .. } => 2,
+        #[cfg(feature = "boole")]
+        ComputeMethod::Boole { .. } => 5,
+        #[cfg(feature = "romberg")]
+        ComputeMethod::Romberg { .. } => 2,
+        #[cfg(feature = "gregory")]
+        ComputeMethod::Gregory { order } => order + 1,
+        #[cfg(feature = "richardson")]
+        ComputeMethod::Richardson { .. } => 2,
+        #[cfg(feature = "simplex")]
+        ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. } => 2,
+        #[cfg(feature = "polar")]
+        ComputeMethod::Lebedev { .. }
+        | ComputeMethod::SphericalProduct { .. }
+        | ComputeMethod::PolarGauss { .. } => 2,
+        #[cfg(feature = "sparse")]
+        ComputeMethod::Smolyak { .. } => 2,
+        #[cfg(feature = "adaptive")]
+        ComputeMethod::GenzMalik { .. } => 2,
+        #[cfg(feature = "montecarlo")]
+        ComputeMethod::MonteCarlo { .. } => 2,
+        #[cfg(feature = "filon")]
+        ComputeMethod::Filon { .. } => 3,
+        #[cfg(feature = "levin")]
+        ComputeMethod::Levin { .. } => 2,
+        #[cfg(feature = "singular")]
+        ComputeMethod::CauchyPrincipalValue { .. } | ComputeMethod::AlgebraicLogarithmic { .. } => {
+            2
+        }
+    }
+}
+
+/// Check that the domain fits the requirements of the method.
+///
+/// This returns `Ok(true)` if the domain is valid but described in decreasing order, which is
+/// only accepted if `allow_descending` is set.
+pub(crate) fn validate<X: Scalar>(
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+    allow_descending: bool,
+) -> Result<bool, IntegraalError> {
+    let descending = match domain {
+        DomainDescriptor::Explicit(args) => {
+            if let Some(idx) = args.iter().position(|x| !x.is_finite()) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "explicit domain contains a non-finite value at index {idx}"
+                )));
+            }
+            if let Some(idx) = args.windows(2).position(|w| w[0] == w[1]) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "explicit domain contains duplicate values at indices {idx} and {}",
+                    idx + 1
+                )));
+            }
+            let ascending = args.windows(2).all(|w| w[0] < w[1]);
+            let descending = !ascending && args.windows(2).all(|w| w[0] > w[1]);
+            if !(ascending || descending && allow_descending) {
+                let idx = args.windows(2).position(|w| w[0] > w[1]).unwrap();
+                return Err(IntegraalError::BadParameters(format!(
+                    "explicit domain values should be sorted in strictly increasing order (x[{idx}] = {:?} > x[{}] = {:?})",
+                    args[idx],
+                    idx + 1,
+                    args[idx + 1],
+                )));
+            }
+            descending
+        }
+        DomainDescriptor::Uniform { start, step, .. } => {
+            if !(start.is_finite() && step.is_finite()) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "uniform domain start and step should be finite (start: {start:?}, step: {step:?})"
+                )));
+            }
+            if step.is_zero() || step.is_sign_negative() && !allow_descending {
+                return Err(IntegraalError::BadParameters(format!(
+                    "uniform domain step should be strictly positive (step: {step:?})"
+                )));
+            }
+            step.is_sign_negative()
+        }
+        DomainDescriptor::Generated(generator) => {
+            validate_generated(generator, allow_descending)?;
+            generator.end < generator.start
+        }
+        DomainDescriptor::Piecewise(pieces) => {
+            validate_pieces(pieces, method)?;
+            return Ok(false);
+        }
+    };
+
+    validate_method(domain, method)?;
+
+    Ok(descending)
+}
+
+/// Check method-specific requirements.
+#[allow(clippy::too_many_lines)]
+fn validate_method<X: Scalar>(
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    let n_sample = domain.n_sample();
+    let required = min_samples(method);
+    if n_sample < required {
+        return Err(IntegraalError::TooFewSamples {
+            method,
+            required,
+            got: n_sample,
+        });
+    }
+
+    match method {
+        #[cfg(feature = "boole")]
+        ComputeMethod::Boole { force } => {
+            require_uniform(domain, method)?;
+            if !force && !(n_sample - 1).is_multiple_of(4) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "domain should be divided into a multiple of 4 segments for Boole's method (segments: {})",
+                    n_sample - 1
+                )));
+            }
+        }
+        #[cfg(feature = "romberg")]
+        ComputeMethod::Romberg { max_steps } => {
+            require_uniform(domain, method)?;
+            if max_steps == 0 || max_steps >= 32 {
+                return Err(IntegraalError::BadParameters(format!(
+                    "Romberg's method requires between 1 and 31 steps (max_steps: {max_steps})"
+                )));
+            }
+        }
+        #[cfg(feature = "open")]
+        ComputeMethod::OpenNewtonCotes { n_points } if n_points == 0 || n_points > 5 => {
+            return Err(IntegraalError::BadParameters(format!(
+                "open Newton-Cotes rules require between 1 and 5 points (n_points: {n_points})"
+            )));
+        }
+        #[cfg(feature = "richardson")]
+        ComputeMethod::Richardson {
+            levels, tolerance, ..
+        } => {
+            require_uniform(domain, method)?;
+            if !(2..=30).contains(&levels) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "Richardson extrapolation requires between 2 and 30 levels (levels: {levels})"
+                )));
+            }
+            if !(tolerance.is_finite() && tolerance > 0.0) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "Richardson extrapolation requires a positive, finite tolerance (tolerance: {tolerance:?})"
+                )));
+            }
+        }
+        #[cfg(feature = "gregory")]
+        ComputeMethod::Gregory { order } => {
+            require_uniform(domain, method)?;
+            if order == 0 || order > 8 {
+                return Err(IntegraalError::BadParameters(format!(
+                    "Gregory's method requires between 1 and 8 correction terms (order: {order})"
+                )));
+            }
+        }
+        #[cfg(feature = "filon")]
+        ComputeMethod::Filon { omega, .. } => {
+            require_uniform(domain, method)?;
+            if !omega.is_finite() {
+                return Err(IntegraalError::BadParameters(format!(
+                    "Filon's method requires a finite angular frequency (omega: {omega:?})"
+                )));
+            }
+            if !(n_sample - 1).is_multiple_of(2) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "domain should be divided into an even number of segments for Filon's method (segments: {})",
+                    n_sample - 1
+                )));
+            }
+        }
+        #[cfg(feature = "levin")]
+        ComputeMethod::Levin { n_nodes } if n_nodes < 2 => {
+            return Err(IntegraalError::BadParameters(format!(
+                "Levin's method requires at least 2 collocation nodes (n_nodes: {n_nodes})"
+            )));
+        }
+        #[cfg(feature = "singular")]
+        ComputeMethod::CauchyPrincipalValue { pole, n_nodes } => {
+            if n_nodes == 0 {
+                return Err(IntegraalError::BadParameters(String::from(
+                    "the Cauchy principal value method requires at least 1 node per segment",
+                )));
+            }
+            let (first, last) = (
+                domain.abscissa(0).to_f64().unwrap(),
+                domain.abscissa(n_sample - 1).to_f64().unwrap(),
+            );
+            if !(first.min(last) < pole && pole < first.max(last)) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "the pole should lie strictly inside the domain (pole: {pole:?}, limits: {first:?} and {last:?})"
+                )));
+            }
+        }
+        #[cfg(feature = "singular")]
+        ComputeMethod::AlgebraicLogarithmic {
+            alpha,
+            beta,
+            n_levels,
+            ..
+        } => {
+            if !(alpha.is_finite() && beta.is_finite() && alpha > -1.0 && beta > -1.0) {
+                return Err(IntegraalError::BadParameters(format!(
+                    "algebraic exponents should be finite and greater than -1 (alpha: {alpha:?}, beta: {beta:?})"
+                )));
+            }
+            if n_levels > 20 {
+                return Err(IntegraalError::BadParameters(format!(
+                    "the algebraic-logarithmic weight method requires at most 20 levels (n_levels: {n_levels})"
+                )));
+            }
+        }
+        #[cfg(feature = "simplex")]
+        ComputeMethod::Dunavant { .. } | ComputeMethod::GrundmannMoller { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "one-dimensional domains",
+            });
+        }
+        #[cfg(feature = "polar")]
+        ComputeMethod::Lebedev { .. }
+        | ComputeMethod::SphericalProduct { .. }
+        | ComputeMethod::PolarGauss { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "one-dimensional domains",
+            });
+        }
+        #[cfg(feature = "sparse")]
+        ComputeMethod::Smolyak { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "one-dimensional domains",
+            });
+        }
+        #[cfg(feature = "adaptive")]
+        ComputeMethod::GenzMalik { .. } => {
+            return Err(IntegraalError::Unimplemented {
+                method,
+                context: "one-dimensional domains",
+            });
+        }
+        #[cfg(feature = "montecarlo")]
+        ComputeMethod::MonteCarlo { n_sample: 0 } => {
+            return Err(IntegraalError::BadParameters(String::from(
+                "the Monte-Carlo method requires at least 1 random sample per step",
+            )));
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Check that the domain is uniform, as required by the method.
+#[cfg(any(
+    feature = "boole",
+    feature = "filon",
+    feature = "gregory",
+    feature = "richardson",
+    feature = "romberg"
+))]
+fn require_uniform<X: Scalar>(
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    if matches!(domain, DomainDescriptor::Uniform { .. }) {
+        Ok(())
+    } else {
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "non-uniform domains",
+        })
+    }
+}
+
+/// Check the limits of a generated domain.
+fn validate_generated<X: Scalar>(
+    generator: &NodeGenerator<X>,
+    allow_descending: bool,
+) -> Result<(), IntegraalError> {
+    let NodeGenerator {
+        spacing,
+        start,
+        end,
+        ..
+    } = generator;
+    if !(start.is_finite() && end.is_finite()) || start == end {
+        return Err(IntegraalError::BadParameters(format!(
+            "generated domain limits should be finite & distinct (start: {start:?}, end: {end:?})"
+        )));
+    }
+    if *spacing == Spacing::Geometric
+        && (start.is_zero() || end.is_zero() || (*start * *end).is_sign_negative())
+    {
+        return Err(IntegraalError::BadParameters(format!(
+            "geometric domain limits should be non-zero & of the same sign (start: {start:?}, end: {end:?})"
+        )));
+    }
+    if end < start && !allow_descending {
+        return Err(IntegraalError::BadParameters(format!(
+            "generated domain limits should be in increasing order (start: {start:?}, end: {end:?})"
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the method can integrate function values over the domain, i.e. that it only
+/// evaluates the function at the domain's samples.
+///
+/// Pieces are checked using their own method if they have one, `method` otherwise.
+pub(crate) fn validate_values<X: Scalar>(
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    if let DomainDescriptor::Piecewise(pieces) = domain {
+        return pieces.iter().try_for_each(|piece| {
+            validate_values(&piece.domain, *piece.method.as_ref().unwrap_or(&method))
+        });
+    }
+    match method {
+        #[cfg(feature = "open")]
+        ComputeMethod::Midpoint | ComputeMethod::OpenNewtonCotes { .. } => {
+            Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            })
+        }
+        #[cfg(feature = "richardson")]
+        ComputeMethod::Richardson { .. } => Err(IntegraalError::Unimplemented {
+            method,
+            context: "function values",
+        }),
+        #[cfg(feature = "levin")]
+        ComputeMethod::Levin { .. } => Err(IntegraalError::Unimplemented {
+            method,
+            context: "non-oscillatory function descriptors",
+        }),
+        #[cfg(feature = "singular")]
+        ComputeMethod::CauchyPrincipalValue { .. } | ComputeMethod::AlgebraicLogarithmic { .. } => {
+            Err(IntegraalError::Unimplemented {
+                method,
+                context: "function values",
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that the method can integrate periodic functions over the domain.
+///
+/// Pieces are checked using their own method if they have one, `method` otherwise.
+pub(crate) fn validate_periodic<X: Scalar>(
+    domain: &DomainDescriptor<X>,
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    if let DomainDescriptor::Piecewise(pieces) = domain {
+        return pieces.iter().try_for_each(|piece| {
+            validate_periodic(&piece.domain, piece.method.unwrap_or(method))
+        });
+    }
+    if !matches!(
+        method,
+        ComputeMethod::RectangleLeft | ComputeMethod::RectangleRight | ComputeMethod::Trapezoid
+    ) {
+        return Err(IntegraalError::Unimplemented {
+            method,
+            context: "periodic functions",
+        });
+    }
+    if !matches!(domain, DomainDescriptor::Uniform { .. }) {
+        return Err(IntegraalError::Unimplemented {
+            method,
+            context: "periodic functions over non-uniform domains",
+        });
+    }
+    Ok(())
+}
+
+/// Check that pieces are valid for their methods, and joined at breakpoints.
+///
+/// Pieces are validated using their own method if they have one, `method` otherwise. Descending
+/// pieces are not supported.
+pub(crate) fn validate_pieces<X: Scalar>(
+    pieces: &[Piece<X>],
+    method: ComputeMethod,
+) -> Result<(), IntegraalError> {
+    if pieces.is_empty() {
+        return Err(IntegraalError::BadParameters(String::from(
+            "piecewise domain should contain at least one piece",
+        )));
+    }
+    for (piece_id, piece) in pieces.iter().enumerate() {
+        if matches!(piece.domain, DomainDescriptor::Piecewise(_)) {
+            return Err(IntegraalError::BadParameters(format!(
+                "nested piecewise domains are not supported (piece {piece_id})"
+            )));
+        }
+        validate(
+            &piece.domain,
+            *piece.method.as_ref().unwrap_or(&method),
+            false,
+        )?;
+    }
+    // uniform pieces may miss the breakpoint by a rounding error
+    let four = X::from(4.0).unwrap();
+    for (piece_id, w) in pieces.windows(2).enumerate() {
+        let end = w[0].domain.abscissa(w[0].domain.n_sample() - 1);
+        let start = w[1].domain.abscissa(0);
+        if (end - start).abs() > four * X::epsilon() * end.abs().max(start.abs()) {
+            return Err(IntegraalError::BadParameters(format!(
+                "pieces should be joined at breakpoints (piece {piece_id} ends at {end:?}, piece {} starts at {start:?})",
+                piece_id + 1
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Return the domain described in increasing order.
+pub(crate) fn reverse<X: Scalar>(domain: &DomainDescriptor<X>) -> DomainDescriptor<X> {
+    match domain {
+        DomainDescriptor::Explicit(args) => {
+            DomainDescriptor::Explicit(args.iter().rev().copied().collect())
+        }
+        DomainDescriptor::Uniform {
+            start,
+            step,
+            n_step,
+        } => {
+            let grid = UniformGrid::new(*start, *step, *n_step).reversed();
+            DomainDescriptor::Uniform {
+                start: grid.start(),
+                step: grid.step(),
+                n_step: grid.n_sample(),
+            }
+        }
+        DomainDescriptor::Generated(generator) => DomainDescriptor::Generated(NodeGenerator {
+            start: generator.end,
+            end: generator.start,
+            ..*generator
+        }),
+        DomainDescriptor::Piecewise(pieces) => DomainDescriptor::Piecewise(
+            pieces
+                .iter()
+                .rev()
+                .map(|piece| Piece {
+                    domain: reverse(&piece.domain),
+                    method: piece.method,
+                })
+                .collect(),
+        ),
+    }
+}
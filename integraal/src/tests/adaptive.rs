@@ -0,0 +1,196 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{Evaluation, NonFinitePolicy, RegionDescriptor};
+
+// ------ TESTS
+
+fn genz_malik(abs_tolerance: f64, rel_tolerance: f64, max_evaluations: usize) -> ComputeMethod {
+    ComputeMethod::GenzMalik {
+        abs_tolerance,
+        rel_tolerance,
+        max_evaluations,
+    }
+}
+
+fn evaluate(
+    function: impl Fn(&[f64]) -> f64 + 'static,
+    bounds: Vec<(f64, f64)>,
+    method: ComputeMethod,
+) -> Result<Evaluation<f64>, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::MultiClosure(Box::new(function)))
+        .region(RegionDescriptor::Hyperrectangle(bounds))
+        .evaluate_detailed()
+}
+
+#[test]
+fn genz_malik_polynomial() {
+    // the rule is exact up to degree 7, and its embedded rule up to degree 5
+    let res = evaluate(
+        |x| x[0].powi(3) * x[1].powi(2) + x[2].powi(5) + 1.0,
+        vec![(0.0, 1.0), (-1.0, 1.0), (0.0, 2.0)],
+        genz_malik(1e-10, 0.0, 1_000),
+    )
+    .unwrap();
+    let expected = 2.0 * (1.0 / 4.0) * (2.0 / 3.0) + 2.0 * (64.0 / 6.0) + 4.0;
+    assert!((res.value - expected).abs() < 1e-12, "{}", res.value);
+    assert!(res.error.unwrap() < 1e-10);
+    assert_eq!(res.evaluations, Some(33));
+}
+
+#[test]
+fn genz_malik_peak() {
+    // sharp gaussian peak, centered in the unit square
+    let f = |x: &[f64]| (-100.0 * ((x[0] - 0.5).powi(2) + (x[1] - 0.5).powi(2))).exp();
+    let expected = std::f64::consts::PI / 100.0;
+    let res = evaluate(f, vec![(0.0, 1.0); 2], genz_malik(1e-9, 0.0, 100_000)).unwrap();
+    assert!((res.value - expected).abs() < 1e-9, "{}", res.value);
+    assert!(res.error.unwrap() <= 1e-9);
+    assert!(res.evaluations.unwrap() <= 100_000);
+    // relative tolerance only
+    let res = evaluate(
+        move |x| 1e6 * f(x),
+        vec![(0.0, 1.0); 2],
+        genz_malik(0.0, 1e-8, 100_000),
+    )
+    .unwrap();
+    assert!(
+        (res.value / 1e6 - expected).abs() < 1e-9 * expected,
+        "{}",
+        res.value
+    );
+
+    // the budget is too small to reach the tolerance
+    assert!(matches!(
+        evaluate(f, vec![(0.0, 1.0); 2], genz_malik(1e-14, 0.0, 200)),
+        Err(IntegraalError::NotConverged { evaluations, .. }) if evaluations <= 200
+    ));
+}
+
+#[test]
+fn genz_malik_vector() {
+    let integral = Integraal::default()
+        .method(genz_malik(1e-10, 0.0, 10_000))
+        .function(FunctionDescriptor::VectorField(Box::new(|x: &[f64]| {
+            vec![1.0, x[0], (x[0] * x[1]).sin()]
+        })))
+        .region(RegionDescriptor::Hyperrectangle(vec![
+            (0.0, 1.0),
+            (0.0, 2.0),
+        ]));
+    let res = integral.evaluate_vector().unwrap();
+    // the last component integrates to Cin(2), the integral of (1 - cos(t)) / t over [0, 2]
+    let cin = (1..20).fold(0.0, |acc, k| {
+        let n = 2 * k;
+        let factorial = (1..=n).map(f64::from).product::<f64>();
+        let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+        acc + sign * 2.0_f64.powi(n) / (f64::from(n) * factorial)
+    });
+    let expected = [2.0, 1.0, cin];
+    assert_eq!(res.len(), 3);
+    for (evaluation, expected) in res.iter().zip(expected) {
+        assert!((evaluation.value - expected).abs() < 1e-9, "{evaluation:?}");
+        assert!(evaluation.error.unwrap() <= 1e-10);
+        assert_eq!(evaluation.evaluations, res[0].evaluations);
+    }
+    // vector fields are not integrated by other entry points
+    assert!(matches!(
+        integral.evaluate(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
+
+#[test]
+fn genz_malik_non_finite() {
+    let f = |x: &[f64]| {
+        if (x[0] - 0.5).abs() < 1e-12 {
+            f64::NAN
+        } else {
+            1.0
+        }
+    };
+    let method = genz_malik(1e-10, 0.0, 1_000);
+    assert_eq!(
+        evaluate(f, vec![(0.0, 1.0); 2], method),
+        Err(IntegraalError::NonFinite {
+            index: 0,
+            abscissa: 0.5,
+        })
+    );
+    let res = Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::MultiClosure(Box::new(f)))
+        .region(RegionDescriptor::Hyperrectangle(vec![(0.0, 1.0); 2]))
+        .non_finite_policy(NonFinitePolicy::ReplaceWithZero)
+        .evaluate();
+    assert!(res.is_ok_and(f64::is_finite));
+}
+
+#[test]
+fn genz_malik_incorrect() {
+    let f = |x: &[f64]| x[0];
+    let unit = || vec![(0.0, 1.0); 2];
+    for method in [
+        genz_malik(0.0, 0.0, 1_000),
+        genz_malik(-1.0, 0.0, 1_000),
+        genz_malik(f64::NAN, 1e-6, 1_000),
+        // the rule uses 17 points in 2D
+        genz_malik(1e-6, 0.0, 16),
+    ] {
+        assert!(matches!(
+            evaluate(f, unit(), method),
+            Err(IntegraalError::BadParameters(_))
+        ));
+    }
+    let method = genz_malik(1e-6, 0.0, 1_000);
+    assert_eq!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::MultiClosure(Box::new(f)))
+            .region(RegionDescriptor::Disc {
+                center: [0.0; 2],
+                radius: 1.0,
+            })
+            .compute(),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "discs",
+        })
+    );
+    assert!(matches!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::MultiClosure(Box::new(f)))
+            .region(RegionDescriptor::Hyperrectangle(unit()))
+            .evaluate_vector(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    assert!(matches!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::VectorField(Box::new(|x: &[f64]| {
+                if x[0] < 0.5 {
+                    vec![1.0]
+                } else {
+                    vec![1.0, 2.0]
+                }
+            })))
+            .region(RegionDescriptor::Hyperrectangle(unit()))
+            .evaluate_vector(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    let method = ComputeMethod::Trapezoid;
+    assert_eq!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::VectorField(Box::new(|x| vec![x[0]])))
+            .region(RegionDescriptor::Hyperrectangle(unit()))
+            .evaluate_vector(),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "regions",
+        })
+    );
+}
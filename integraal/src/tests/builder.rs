@@ -0,0 +1,205 @@
+// ------ IMPORTS
+
+use super::*;
+
+// ------ TESTS
+
+#[test]
+fn build() {
+    let integral = Integraal::builder()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| 2.0 * x)))
+        .domain(DomainDescriptor::Uniform {
+            start: 0.0,
+            step: 0.1,
+            n_step: 11,
+        })
+        .build()
+        .unwrap();
+    let res = integral.evaluate().unwrap();
+    assert!((res - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn build_errors() {
+    let builder = Integraal::builder()
+        .function(FunctionDescriptor::Values(vec![1.0, 1.0, 1.0]))
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.5]));
+
+    assert!(matches!(
+        builder.method(ComputeMethod::Simpson).build(),
+        Err(IntegraalError::TooFewSamples { .. })
+    ));
+
+    let builder = Integraal::builder()
+        .function(FunctionDescriptor::Values(vec![1.0, 1.0, 1.0]))
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.5]));
+    assert!(matches!(
+        builder.method(ComputeMethod::Trapezoid).build(),
+        Err(IntegraalError::LengthMismatch {
+            domain: 2,
+            function: 3
+        })
+    ));
+}
+
+#[test]
+fn build_periodic() {
+    let builder = |domain| {
+        Integraal::builder()
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| x.sin())))
+            .domain(domain)
+            .periodic(true)
+    };
+    let uniform = || DomainDescriptor::Uniform {
+        start: 0.0,
+        step: 0.25,
+        n_step: 8,
+    };
+    assert!(builder(uniform())
+        .method(ComputeMethod::Trapezoid)
+        .build()
+        .is_ok());
+    assert!(matches!(
+        builder(uniform()).method(ComputeMethod::Simpson).build(),
+        Err(IntegraalError::Unimplemented {
+            method: ComputeMethod::Simpson,
+            context: "periodic functions",
+        })
+    ));
+    assert!(matches!(
+        builder(DomainDescriptor::Explicit(vec![0.0, 0.5, 1.0, 1.5]))
+            .method(ComputeMethod::Trapezoid)
+            .build(),
+        Err(IntegraalError::Unimplemented {
+            context: "periodic functions over non-uniform domains",
+            ..
+        })
+    ));
+}
+
+#[cfg(feature = "boole")]
+#[test]
+fn build_non_uniform_boole() {
+    let res = Integraal::builder()
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.1, 0.2, 0.3, 0.4]))
+        .method(ComputeMethod::Boole { force: false })
+        .build();
+    assert!(matches!(
+        res,
+        Err(IntegraalError::Unimplemented {
+            context: "non-uniform domains",
+            ..
+        })
+    ));
+}
+
+#[cfg(feature = "romberg")]
+#[test]
+fn build_non_uniform_romberg() {
+    let res = Integraal::builder()
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.1, 0.2, 0.3, 0.4]))
+        .method(ComputeMethod::Romberg { max_steps: 5 })
+        .build();
+    assert!(matches!(
+        res,
+        Err(IntegraalError::Unimplemented {
+            context: "non-uniform domains",
+            ..
+        })
+    ));
+}
+
+#[cfg(feature = "richardson")]
+#[test]
+fn build_non_uniform_richardson() {
+    let res = Integraal::builder()
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.1, 0.2, 0.3, 0.4]))
+        .method(ComputeMethod::Richardson {
+            base: crate::BaseRule::Trapezoid,
+            levels: 4,
+            tolerance: 1e-8,
+        })
+        .build();
+    assert!(matches!(
+        res,
+        Err(IntegraalError::Unimplemented {
+            context: "non-uniform domains",
+            ..
+        })
+    ));
+}
+
+#[cfg(feature = "open")]
+#[test]
+fn build_values_off_grid() {
+    let builder = || {
+        Integraal::builder()
+            .function(FunctionDescriptor::Values(vec![1.0; 11]))
+            .domain(DomainDescriptor::linspace(0.0, 1.0, 11))
+    };
+    assert!(matches!(
+        builder().method(ComputeMethod::Midpoint).build(),
+        Err(IntegraalError::Unimplemented {
+            context: "function values",
+            ..
+        })
+    ));
+    assert!(matches!(
+        builder()
+            .method(ComputeMethod::OpenNewtonCotes { n_points: 2 })
+            .build(),
+        Err(IntegraalError::Unimplemented {
+            context: "function values",
+            ..
+        })
+    ));
+
+    // pieces are checked using their own method
+    let res = Integraal::builder()
+        .function(FunctionDescriptor::Values(vec![1.0; 6]))
+        .domain(DomainDescriptor::Piecewise(vec![
+            Piece::new(DomainDescriptor::linspace(0.0, 0.5, 3)),
+            Piece::new(DomainDescriptor::linspace(0.5, 1.0, 3)).method(ComputeMethod::Midpoint),
+        ]))
+        .method(ComputeMethod::Trapezoid)
+        .build();
+    assert!(matches!(
+        res,
+        Err(IntegraalError::Unimplemented {
+            context: "function values",
+            ..
+        })
+    ));
+
+    // closures can still be evaluated between samples
+    assert!(Integraal::builder()
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::linspace(0.0, 1.0, 11))
+        .method(ComputeMethod::Midpoint)
+        .build()
+        .is_ok());
+}
+
+#[cfg(feature = "singular")]
+#[test]
+fn build_values_singular() {
+    let res = Integraal::builder()
+        .function(FunctionDescriptor::Values(vec![1.0; 11]))
+        .domain(DomainDescriptor::linspace(0.0, 1.0, 11))
+        .method(ComputeMethod::CauchyPrincipalValue {
+            pole: 0.55,
+            n_nodes: 4,
+        })
+        .build();
+    assert!(matches!(
+        res,
+        Err(IntegraalError::Unimplemented {
+            context: "function values",
+            ..
+        })
+    ));
+}
@@ -0,0 +1,147 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{CurveDescriptor, RegionDescriptor};
+use std::{f64::consts::TAU, sync::Arc};
+
+// ------ TESTS
+
+fn circle(n_step: usize, with_derivative: bool) -> RegionDescriptor<f64> {
+    RegionDescriptor::Curve(CurveDescriptor::Parametric {
+        point: Arc::new(|t: f64| vec![t.cos(), t.sin()]),
+        derivative: with_derivative
+            .then(|| Arc::new(|t: f64| vec![-t.sin(), t.cos()]) as Arc<dyn Fn(f64) -> Vec<f64>>),
+        parameter: DomainDescriptor::Uniform {
+            start: 0.0,
+            step: TAU / n_step as f64,
+            n_step,
+        },
+    })
+}
+
+#[test]
+fn parametric() {
+    // arc length; the parameter describes a single period
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::MultiClosure(Box::new(|_: &[f64]| 1.0)))
+        .region(circle(32, true))
+        .periodic(true)
+        .compute()
+        .unwrap();
+    assert!((res - TAU).abs() < 1e-12, "{res}");
+
+    // circulation of (-y, x), using finite differences
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::VectorField(Box::new(|p: &[f64]| {
+            vec![-p[1], p[0]]
+        })))
+        .region(circle(32, false))
+        .periodic(true)
+        .compute()
+        .unwrap();
+    assert!((res - TAU).abs() < 1e-8, "{res}");
+
+    // length of a helix turn
+    let helix = CurveDescriptor::Parametric {
+        point: Arc::new(|t: f64| vec![t.cos(), t.sin(), t]),
+        derivative: None,
+        parameter: DomainDescriptor::linspace(0.0, TAU, 1001),
+    };
+    let res = Integraal::builder()
+        .region(RegionDescriptor::Curve(helix))
+        .function(FunctionDescriptor::MultiClosure(Box::new(|_: &[f64]| 1.0)))
+        .method(ComputeMethod::Simpson)
+        .build()
+        .unwrap()
+        .evaluate()
+        .unwrap();
+    assert!((res - TAU * 2.0_f64.sqrt()).abs() < 1e-8, "{res}");
+}
+
+#[test]
+fn polyline() {
+    // integral of x along a segment of length 5
+    let segment = CurveDescriptor::Polyline {
+        vertices: vec![vec![0.0, 0.0], vec![3.0, 4.0]],
+        n_sample: 2,
+    };
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::MultiClosure(Box::new(|p: &[f64]| p[0])))
+        .region(RegionDescriptor::Curve(segment))
+        .compute()
+        .unwrap();
+    assert!((res - 7.5).abs() < 1e-12, "{res}");
+
+    // circulation of (-y, x) around the unit square is twice its area
+    let square = CurveDescriptor::Polyline {
+        vertices: vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+        ],
+        n_sample: 3,
+    };
+    let res = Integraal::default()
+        .method(ComputeMethod::Simpson)
+        .function(FunctionDescriptor::VectorField(Box::new(|p: &[f64]| {
+            vec![-p[1], p[0]]
+        })))
+        .region(RegionDescriptor::Curve(square))
+        .compute()
+        .unwrap();
+    assert!((res - 2.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn curve_incorrect() {
+    let segment = |vertices, n_sample| {
+        RegionDescriptor::Curve(CurveDescriptor::Polyline { vertices, n_sample })
+    };
+    let compute = |region| {
+        Integraal::default()
+            .method(ComputeMethod::Trapezoid)
+            .function(FunctionDescriptor::MultiClosure(Box::new(|_: &[f64]| 1.0)))
+            .region(region)
+            .compute()
+    };
+    assert!(matches!(
+        compute(segment(vec![vec![0.0, 0.0]], 2)),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        compute(segment(vec![vec![0.0, 0.0], vec![1.0]], 2)),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert_eq!(
+        compute(segment(vec![vec![0.0], vec![1.0]], 1)),
+        Err(IntegraalError::TooFewSamples {
+            method: ComputeMethod::Trapezoid,
+            required: 2,
+            got: 1,
+        })
+    );
+    // scalar closures cannot be integrated along curves, nor vector fields over domains
+    assert!(matches!(
+        Integraal::default()
+            .method(ComputeMethod::Trapezoid)
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+            .region(circle(8, true))
+            .compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    assert!(matches!(
+        Integraal::default()
+            .method(ComputeMethod::Trapezoid)
+            .function(FunctionDescriptor::VectorField(Box::new(|p: &[f64]| {
+                p.to_vec()
+            })))
+            .domain(DomainDescriptor::linspace(0.0, 1.0, 3))
+            .compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
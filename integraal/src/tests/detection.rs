@@ -0,0 +1,125 @@
+// ------ IMPORTS
+
+use super::*;
+
+// ------ TESTS
+
+// integral B (contiguous, non-contiguous derivative)
+fn function_b(x: f64) -> f64 {
+    if x < 1.0 {
+        x
+    } else if x < 2.0 {
+        1.0
+    } else {
+        3.0 - x
+    }
+}
+
+// integral C (piece-wise contiguous)
+fn function_c(x: f64) -> f64 {
+    if (1.0..1.5).contains(&x) {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn evaluate(
+    function: fn(f64) -> f64,
+    domain: DomainDescriptor<f64>,
+    method: ComputeMethod,
+) -> Evaluation<f64> {
+    let vals = (0..domain.n_sample())
+        .map(|i| function(domain.abscissa(i)))
+        .collect();
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Values(vals))
+        .domain(domain)
+        .detect_discontinuities(DiscontinuityDetection::default())
+        .evaluate_detailed()
+        .unwrap()
+}
+
+#[test]
+fn kinks_on_samples() {
+    let res = evaluate(
+        function_b,
+        DomainDescriptor::linspace(0.0, 3.0, 3001),
+        ComputeMethod::Simpson,
+    );
+    assert!((res.value - 2.0).abs() < 1e-12, "{}", res.value);
+    assert_eq!(res.breakpoints.len(), 2, "{:?}", res.breakpoints);
+    for (bp, x) in res.breakpoints.iter().zip([1.0, 2.0]) {
+        assert_eq!(bp.kind, Discontinuity::Kink);
+        assert!((bp.start - x).abs() < 1e-12 && (bp.end - x).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn kinks_between_samples() {
+    let res = evaluate(
+        function_b,
+        DomainDescriptor::linspace(0.0, 3.0, 1001),
+        ComputeMethod::Simpson,
+    );
+    assert_eq!(res.breakpoints.len(), 2, "{:?}", res.breakpoints);
+    for (bp, x) in res.breakpoints.iter().zip([1.0, 2.0]) {
+        assert_eq!(bp.kind, Discontinuity::Kink);
+        assert!(bp.start < x && x < bp.end, "{bp:?}");
+    }
+    // only the segments containing a kink are approximated
+    assert!((res.value - 2.0).abs() < 1e-5, "{}", res.value);
+}
+
+#[test]
+fn jumps() {
+    let res = evaluate(
+        function_c,
+        DomainDescriptor::linspace(0.0, 2.0, 1000),
+        ComputeMethod::Simpson,
+    );
+    assert_eq!(res.breakpoints.len(), 2, "{:?}", res.breakpoints);
+    for (bp, x) in res.breakpoints.iter().zip([1.0, 1.5]) {
+        assert_eq!(bp.kind, Discontinuity::Jump);
+        assert!(bp.start < x && x < bp.end, "{bp:?}");
+    }
+    // the error is bounded by the width of segments containing a jump
+    assert!((res.value - 0.5).abs() < 2.0 / 999.0, "{}", res.value);
+}
+
+#[test]
+fn smooth() {
+    // no false positives on smooth functions, including around extrema & inflection points
+    for function in [f64::sin, f64::exp, |x: f64| x.powi(3) - x] {
+        let res = evaluate(
+            function,
+            DomainDescriptor::linspace(-3.0, 3.0, 601),
+            ComputeMethod::Simpson,
+        );
+        assert!(res.breakpoints.is_empty(), "{:?}", res.breakpoints);
+    }
+}
+
+#[test]
+fn disabled() {
+    // detection is disabled by default, and never applies to closures
+    let domain = DomainDescriptor::linspace(0.0, 3.0, 1000);
+    let res = Integraal::default()
+        .method(ComputeMethod::Simpson)
+        .function(FunctionDescriptor::Values(
+            (0..1000).map(|i| function_b(domain.abscissa(i))).collect(),
+        ))
+        .domain(domain.clone())
+        .evaluate_detailed()
+        .unwrap();
+    assert!(res.breakpoints.is_empty());
+    let res = Integraal::default()
+        .method(ComputeMethod::Simpson)
+        .function(FunctionDescriptor::Closure(Box::new(function_b)))
+        .domain(domain)
+        .detect_discontinuities(DiscontinuityDetection::default())
+        .evaluate_detailed()
+        .unwrap();
+    assert!(res.breakpoints.is_empty());
+}
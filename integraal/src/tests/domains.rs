@@ -0,0 +1,215 @@
+// ------ IMPORTS
+
+use super::*;
+
+// ------ TESTS
+
+// y = f(x) = 2x from 1 to 0, i.e. -1
+
+#[test]
+fn descending_explicit() {
+    let args: Vec<f64> = (0..=10).rev().map(|i| f64::from(i) * 0.1).collect();
+    let vals: Vec<f64> = args.iter().map(|x| 2.0 * x).collect();
+
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Values(vals))
+        .domain(DomainDescriptor::Explicit(args.clone()))
+        .allow_descending(true);
+    let res = integral.compute().unwrap();
+    assert!((res + 1.0).abs() < 1e-12);
+
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::Simpson)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| 2.0 * x)))
+        .domain(DomainDescriptor::Explicit(args))
+        .allow_descending(true);
+    let res = integral.compute().unwrap();
+    assert!((res + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn descending_uniform() {
+    let domain = DomainDescriptor::Uniform {
+        start: 1.0,
+        step: -0.1,
+        n_step: 11,
+    };
+
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::RectangleLeft)
+        .function(FunctionDescriptor::Closure(Box::new(|_: f64| 2.0)))
+        .domain(domain.clone())
+        .allow_descending(true);
+    let res = integral.compute().unwrap();
+    assert!((res + 2.0).abs() < 1e-12);
+
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Values(
+            (0..11).rev().map(|i| 0.2 * f64::from(i)).collect(),
+        ))
+        .domain(domain)
+        .allow_descending(true);
+    let res = integral.compute().unwrap();
+    assert!((res + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn descending_non_finite_index() {
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Values(vec![1.0, f64::NAN, 1.0, 1.0]))
+        .domain(DomainDescriptor::Explicit(vec![0.3, 0.2, 0.1, 0.0]))
+        .allow_descending(true);
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::NonFinite {
+            index: 1,
+            abscissa: 0.2,
+        })
+    );
+}
+
+// constructors
+
+fn explicit(domain: &DomainDescriptor<f64>) -> &[f64] {
+    let DomainDescriptor::Explicit(args) = domain else {
+        panic!("expected an explicit domain")
+    };
+    args
+}
+
+#[test]
+fn linspace() {
+    let domain: DomainDescriptor<f64> = DomainDescriptor::linspace(1.0, 3.0, 5);
+    assert!(matches!(
+        domain,
+        DomainDescriptor::Uniform { start, step, n_step: 5 }
+            if (start - 1.0).abs() < 1e-15 && (step - 0.5).abs() < 1e-15
+    ));
+    let res = Integraal::default()
+        .method(ComputeMethod::Simpson)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x * x)))
+        .domain(domain)
+        .compute()
+        .unwrap();
+    assert!((res - 26.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn logspace_geomspace() {
+    let domain = DomainDescriptor::logspace(-3.0, 3.0, 7);
+    let args = explicit(&domain);
+    for (i, x) in (-3..=3).zip(args) {
+        let expected = 10.0_f64.powi(i);
+        assert!((x - expected).abs() < expected * 1e-14, "{x} vs {expected}");
+    }
+
+    let domain = DomainDescriptor::geomspace(2.0, 32.0, 5);
+    let args = explicit(&domain);
+    assert_eq!(args[0].to_bits(), 2.0_f64.to_bits());
+    assert_eq!(args[4].to_bits(), 32.0_f64.to_bits());
+    assert!((args[2] - 8.0).abs() < 1e-14);
+}
+
+#[test]
+fn chebyshev() {
+    let domain = DomainDescriptor::chebyshev_lobatto(0.0, 2.0, 5);
+    let args = explicit(&domain);
+    let expected = [0.0, 1.0 - 0.5_f64.sqrt(), 1.0, 1.0 + 0.5_f64.sqrt(), 2.0];
+    for (x, e) in args.iter().zip(expected) {
+        assert!((x - e).abs() < 1e-14, "{x} vs {e}");
+    }
+
+    let domain = DomainDescriptor::chebyshev_gauss(-1.0, 1.0, 3);
+    let args = explicit(&domain);
+    let expected = [-(0.75_f64.sqrt()), 0.0, 0.75_f64.sqrt()];
+    for (x, e) in args.iter().zip(expected) {
+        assert!((x - e).abs() < 1e-14, "{x} vs {e}");
+    }
+}
+
+#[test]
+fn gauss_legendre() {
+    // roots of P_4
+    let (a, b) = (
+        (3.0 / 7.0 - 2.0 / 7.0 * 1.2_f64.sqrt()).sqrt(),
+        (3.0 / 7.0 + 2.0 / 7.0 * 1.2_f64.sqrt()).sqrt(),
+    );
+    let domain = DomainDescriptor::gauss_legendre(-1.0, 1.0, 4);
+    let args = explicit(&domain);
+    for (x, e) in args.iter().zip([-b, -a, a, b]) {
+        assert!((x - e).abs() < 1e-14, "{x} vs {e}");
+    }
+    // nodes are strictly increasing, even for large counts
+    let domain = DomainDescriptor::gauss_legendre(0.0, 1.0, 200);
+    assert!(explicit(&domain).windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn generated() {
+    // generated domains yield the same results as their explicit counterpart
+    for spacing in [
+        Spacing::Linear,
+        Spacing::Geometric,
+        Spacing::ChebyshevGauss,
+        Spacing::ChebyshevLobatto,
+        Spacing::GaussLegendre,
+    ] {
+        let generator = NodeGenerator::new(spacing, 1.0, 4.0, 101);
+        for method in [ComputeMethod::Trapezoid, ComputeMethod::Simpson] {
+            let lazy = Integraal::default()
+                .method(method)
+                .function(FunctionDescriptor::Closure(Box::new(f64::sqrt)))
+                .domain(DomainDescriptor::Generated(generator))
+                .compute()
+                .unwrap();
+            let eager = Integraal::default()
+                .method(method)
+                .function(FunctionDescriptor::Closure(Box::new(f64::sqrt)))
+                .domain(generator.explicit())
+                .compute()
+                .unwrap();
+            assert_eq!(lazy.to_bits(), eager.to_bits(), "{spacing:?}");
+        }
+    }
+
+    // descending generated domain
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| 2.0 * x)))
+        .domain(DomainDescriptor::Generated(NodeGenerator::new(
+            Spacing::Geometric,
+            1.0,
+            0.1,
+            11,
+        )))
+        .allow_descending(true)
+        .compute()
+        .unwrap();
+    assert!((res + 0.99).abs() < 1e-2, "{res}");
+}
+
+#[test]
+fn bad_generated() {
+    for (spacing, start, end) in [
+        (Spacing::Linear, 1.0, 1.0),
+        (Spacing::Linear, 0.0, f64::INFINITY),
+        (Spacing::Linear, 1.0, 0.0),
+        (Spacing::Geometric, -1.0, 1.0),
+        (Spacing::Geometric, 0.0, 1.0),
+    ] {
+        let res = Integraal::default()
+            .method(ComputeMethod::Trapezoid)
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+            .domain(DomainDescriptor::Generated(NodeGenerator::new(
+                spacing, start, end, 11,
+            )))
+            .compute();
+        assert!(
+            matches!(res, Err(IntegraalError::BadParameters(_))),
+            "{spacing:?}: {res:?}"
+        );
+    }
+}
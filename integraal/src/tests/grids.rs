@@ -0,0 +1,175 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{NonFinitePolicy, RegionDescriptor};
+
+// ------ TESTS
+
+fn compute(
+    vals: Vec<f64>,
+    shape: Vec<usize>,
+    spacing: Vec<f64>,
+    mask: Option<Vec<bool>>,
+    method: ComputeMethod,
+) -> Result<f64, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Values(vals))
+        .region(RegionDescriptor::Grid {
+            shape,
+            spacing,
+            mask,
+        })
+        .compute()
+}
+
+#[test]
+fn grid_2d() {
+    // x^2 * y over [0, 2] x [0, 1]; y is the first axis, x the second
+    let vals = (0..3)
+        .flat_map(|j| (0..5).map(move |i| (0.5 * f64::from(i)).powi(2) * 0.5 * f64::from(j)))
+        .collect();
+    let res = compute(
+        vals,
+        vec![3, 5],
+        vec![0.5, 0.5],
+        None,
+        ComputeMethod::Simpson,
+    )
+    .unwrap();
+    assert!((res - 4.0 / 3.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn grid_3d() {
+    let res = compute(
+        vec![1.0; 24],
+        vec![2, 3, 4],
+        vec![1.0, 0.5, 0.25],
+        None,
+        ComputeMethod::Trapezoid,
+    )
+    .unwrap();
+    assert!((res - 0.75).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn grid_mask() {
+    // only the center sample is kept; values outside the region are ignored
+    let mut vals = vec![f64::NAN; 9];
+    vals[4] = 2.0;
+    let mut mask = vec![false; 9];
+    mask[4] = true;
+    let res = compute(
+        vals.clone(),
+        vec![3, 3],
+        vec![1.0, 1.0],
+        Some(mask),
+        ComputeMethod::Trapezoid,
+    )
+    .unwrap();
+    assert!((res - 2.0).abs() < 1e-12, "{res}");
+
+    // without a mask, the first non-finite value is reported using its index in the buffer
+    vals[0] = 1.0;
+    assert_eq!(
+        compute(
+            vals,
+            vec![3, 3],
+            vec![1.0, 1.0],
+            None,
+            ComputeMethod::Trapezoid
+        ),
+        Err(IntegraalError::NonFinite {
+            index: 1,
+            abscissa: 1.0,
+        })
+    );
+    let skip = |vals: Vec<f64>, shape: Vec<usize>| {
+        Integraal::default()
+            .method(ComputeMethod::Trapezoid)
+            .function(FunctionDescriptor::Values(vals))
+            .region(RegionDescriptor::Grid {
+                shape,
+                spacing: vec![1.0, 1.0],
+                mask: None,
+            })
+            .non_finite_policy(NonFinitePolicy::Skip)
+            .compute()
+    };
+    let res = skip(vec![1.0, f64::NAN, 1.0, 1.0, 1.0, 1.0], vec![2, 3]).unwrap();
+    assert!((res - 2.0).abs() < 1e-12, "{res}");
+    // lines should keep enough samples for the method
+    assert_eq!(
+        skip(vec![1.0, f64::NAN, 1.0, 1.0], vec![2, 2]),
+        Err(IntegraalError::TooFewSamples {
+            method: ComputeMethod::Trapezoid,
+            required: 2,
+            got: 1,
+        })
+    );
+}
+
+#[test]
+fn grid_incorrect() {
+    let method = ComputeMethod::Trapezoid;
+    assert_eq!(
+        compute(vec![1.0; 5], vec![2, 3], vec![1.0, 1.0], None, method),
+        Err(IntegraalError::LengthMismatch {
+            domain: 6,
+            function: 5,
+        })
+    );
+    assert!(matches!(
+        compute(vec![1.0; 6], vec![2, 3], vec![1.0], None, method),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        compute(vec![1.0; 6], vec![2, 3], vec![1.0, -1.0], None, method),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        compute(
+            vec![1.0; 6],
+            vec![2, 3],
+            vec![1.0, 1.0],
+            Some(vec![true; 5]),
+            method
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert_eq!(
+        compute(vec![1.0; 3], vec![1, 3], vec![1.0, 1.0], None, method),
+        Err(IntegraalError::TooFewSamples {
+            method,
+            required: 2,
+            got: 1,
+        })
+    );
+    #[cfg(feature = "boole")]
+    assert_eq!(
+        compute(
+            vec![1.0; 25],
+            vec![5, 5],
+            vec![1.0, 1.0],
+            None,
+            ComputeMethod::Boole { force: false }
+        ),
+        Err(IntegraalError::Unimplemented {
+            method: ComputeMethod::Boole { force: false },
+            context: "grids",
+        })
+    );
+    assert!(matches!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::MultiClosure(Box::new(|_: &[f64]| 1.0)))
+            .region(RegionDescriptor::Grid {
+                shape: vec![2, 2],
+                spacing: vec![1.0, 1.0],
+                mask: None,
+            })
+            .compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
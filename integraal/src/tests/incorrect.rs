@@ -9,23 +9,23 @@ use super::*;
 fn missing_parameters() {
     // missing function descriptor
     generate_sample_descriptors!(function, domain, method);
-    generate_missing!(domain, method);
+    generate_missing!(domain, method, "function");
 
     // missing domain descriptor
     generate_sample_descriptors!(function, domain, method);
-    generate_missing!(function, method);
+    generate_missing!(function, method, "domain");
 
     // missing compute method
     generate_sample_descriptors!(function, domain, method);
-    generate_missing!(function, domain);
+    generate_missing!(function, domain, "method");
 
     // missing all but one
     let mut integral: Integraal<f64> = Integraal::default().method(method);
     assert_eq!(
         integral.compute(),
-        Err(IntegraalError::MissingParameters(
-            "one or more parameter is missing"
-        ))
+        Err(IntegraalError::MissingParameters(vec![
+            "domain", "function"
+        ]))
     );
 }
 
@@ -41,9 +41,10 @@ fn inconsistent_parameters() {
         .domain(domain);
     assert_eq!(
         integral.compute(),
-        Err(IntegraalError::InconsistentParameters(
-            "function and domain value slices have different lengthes"
-        ))
+        Err(IntegraalError::LengthMismatch {
+            domain: 5,
+            function: 6
+        })
     );
 
     // equivalent to the first domain
@@ -60,8 +61,153 @@ fn inconsistent_parameters() {
         .domain(domain);
     assert_eq!(
         integral.compute(),
-        Err(IntegraalError::InconsistentParameters(
-            "provided function and domain value slices have different lengthes"
-        ))
+        Err(IntegraalError::LengthMismatch {
+            domain: 5,
+            function: 6
+        })
+    );
+}
+
+#[test]
+fn bad_domains() {
+    let method = ComputeMethod::Trapezoid;
+
+    let mut integral = Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.2, 0.1, 0.3]));
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::BadParameters(String::from(
+            "explicit domain values should be sorted in strictly increasing order (x[1] = 0.2 > x[2] = 0.1)"
+        )))
+    );
+
+    let mut integral = Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.1, 0.1, 0.3]));
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::BadParameters(String::from(
+            "explicit domain contains duplicate values at indices 1 and 2"
+        )))
+    );
+
+    let mut integral = Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Uniform {
+            start: 0.0,
+            step: 0.0,
+            n_step: 10,
+        });
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::BadParameters(String::from(
+            "uniform domain step should be strictly positive (step: 0.0)"
+        )))
+    );
+
+    // descending domains are rejected by default
+    let mut integral = Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Uniform {
+            start: 1.0,
+            step: -0.1,
+            n_step: 10,
+        });
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::BadParameters(String::from(
+            "uniform domain step should be strictly positive (step: -0.1)"
+        )))
+    );
+}
+
+#[test]
+fn too_few_samples() {
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Values(vec![1.0]))
+        .domain(DomainDescriptor::Explicit(vec![0.0]));
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::TooFewSamples {
+            method: ComputeMethod::Trapezoid,
+            required: 2,
+            got: 1
+        })
+    );
+
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::Simpson)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(DomainDescriptor::Uniform {
+            start: 0.0,
+            step: 0.1,
+            n_step: 2,
+        });
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::TooFewSamples {
+            method: ComputeMethod::Simpson,
+            required: 3,
+            got: 2
+        })
+    );
+}
+
+#[cfg(feature = "boole")]
+#[test]
+fn boole_segments() {
+    let domain = DomainDescriptor::Uniform {
+        start: 0.0,
+        step: 0.1,
+        n_step: 8,
+    };
+    let mut integral = Integraal::default()
+        .method(ComputeMethod::Boole { force: false })
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+        .domain(domain);
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::BadParameters(String::from(
+            "domain should be divided into a multiple of 4 segments for Boole's method (segments: 7)"
+        )))
+    );
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(
+        IntegraalError::MissingParameters(vec!["domain", "method"]).to_string(),
+        "missing parameter(s): domain, method"
+    );
+    assert_eq!(
+        IntegraalError::LengthMismatch {
+            domain: 5,
+            function: 6
+        }
+        .to_string(),
+        "function and domain descriptors have different lengths (domain: 5, function: 6)"
+    );
+    assert_eq!(
+        IntegraalError::TooFewSamples {
+            method: ComputeMethod::Simpson,
+            required: 3,
+            got: 2
+        }
+        .to_string(),
+        "Simpson's rule requires at least 3 samples, got 2"
+    );
+    assert_eq!(
+        IntegraalError::NotConverged {
+            achieved_error: 1e-3,
+            evaluations: 100
+        }
+        .to_string(),
+        "computation did not converge (achieved error: 1e-3, evaluations: 100)"
     );
 }
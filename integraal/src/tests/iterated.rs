@@ -0,0 +1,154 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{IteratedLevel, RegionDescriptor};
+use std::{f64::consts::PI, sync::Arc};
+
+// ------ TESTS
+
+fn compute(
+    function: impl Fn(&[f64]) -> f64 + 'static,
+    levels: Vec<IteratedLevel<f64>>,
+    method: ComputeMethod,
+) -> Result<f64, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::MultiClosure(Box::new(function)))
+        .region(RegionDescriptor::Iterated(levels))
+        .compute()
+}
+
+// lower triangle of the unit square, i.e. 0 <= y <= x <= 1
+fn triangle(n: usize) -> Vec<IteratedLevel<f64>> {
+    vec![
+        IteratedLevel::constant(0.0, 1.0, n),
+        IteratedLevel::new(Arc::new(|_| 0.0), Arc::new(|x: &[f64]| x[0]), n),
+    ]
+}
+
+#[test]
+fn triangle_polynomial() {
+    // integrands of both levels are polynomials of degree <= 3
+    let res = compute(|x| x[0] * x[1], triangle(3), ComputeMethod::Simpson).unwrap();
+    assert!((res - 0.125).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn method_per_level() {
+    // the inner integrand is linear, so it is integrated exactly by the trapezoid rule
+    let mut levels = triangle(3);
+    levels[1] = levels[1].clone().method(ComputeMethod::Trapezoid);
+    let res = compute(|x| x[0] * x[1], levels, ComputeMethod::Simpson).unwrap();
+    assert!((res - 0.125).abs() < 1e-12, "{res}");
+
+    // methods of levels are checked independently
+    let levels = vec![
+        IteratedLevel::constant(0.0, 1.0, 2).method(ComputeMethod::Trapezoid),
+        IteratedLevel::constant(0.0, 1.0, 3),
+    ];
+    let res = compute(|x| x[1] * x[1], levels, ComputeMethod::Simpson).unwrap();
+    assert!((res - 1.0 / 3.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn tetrahedron() {
+    // volume of the unit tetrahedron
+    let levels = vec![
+        IteratedLevel::constant(0.0, 1.0, 3),
+        IteratedLevel::new(Arc::new(|_| 0.0), Arc::new(|x: &[f64]| 1.0 - x[0]), 3),
+        IteratedLevel::new(
+            Arc::new(|_| 0.0),
+            Arc::new(|x: &[f64]| 1.0 - x[0] - x[1]),
+            3,
+        ),
+    ];
+    let res = compute(|_| 1.0, levels, ComputeMethod::Simpson).unwrap();
+    assert!((res - 1.0 / 6.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn disc() {
+    // area of the unit disc; limits of the inner level are equal at both ends of the outer one
+    let half_chord = |x: &[f64]| (1.0 - x[0] * x[0]).max(0.0).sqrt();
+    let levels = vec![
+        IteratedLevel::constant(-1.0, 1.0, 2001),
+        IteratedLevel::new(Arc::new(move |x| -half_chord(x)), Arc::new(half_chord), 2)
+            .method(ComputeMethod::Trapezoid),
+    ];
+    let res = compute(|_| 1.0, levels, ComputeMethod::Simpson).unwrap();
+    assert!((res - PI).abs() < 1e-4, "{res}");
+}
+
+#[test]
+fn descending_limits() {
+    // upper triangle of the unit square, described with reversed inner limits
+    let levels = || {
+        vec![
+            IteratedLevel::constant(0.0, 1.0, 3),
+            IteratedLevel::new(Arc::new(|_| 1.0), Arc::new(|x: &[f64]| x[0]), 3),
+        ]
+    };
+    assert!(matches!(
+        compute(|x| x[1], levels(), ComputeMethod::Simpson),
+        Err(IntegraalError::BadParameters(_))
+    ));
+
+    let res = Integraal::default()
+        .method(ComputeMethod::Simpson)
+        .function(FunctionDescriptor::MultiClosure(Box::new(|x| x[1])))
+        .region(RegionDescriptor::Iterated(levels()))
+        .allow_descending(true)
+        .compute()
+        .unwrap();
+    assert!((res + 1.0 / 3.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn incorrect() {
+    // no levels
+    assert!(matches!(
+        compute(|_| 1.0, Vec::new(), ComputeMethod::Simpson),
+        Err(IntegraalError::BadParameters(_))
+    ));
+
+    // too few samples for the method of a level
+    let levels = vec![
+        IteratedLevel::constant(0.0, 1.0, 3),
+        IteratedLevel::constant(0.0, 1.0, 3).method(ComputeMethod::Trapezoid),
+    ];
+    assert!(compute(|_| 1.0, levels, ComputeMethod::Simpson).is_ok());
+    assert_eq!(
+        compute(|_| 1.0, triangle(2), ComputeMethod::Simpson),
+        Err(IntegraalError::TooFewSamples {
+            method: ComputeMethod::Simpson,
+            required: 3,
+            got: 2,
+        })
+    );
+
+    // non-finite limits
+    let levels = vec![
+        IteratedLevel::constant(0.0, 1.0, 3),
+        IteratedLevel::new(Arc::new(|_| 0.0), Arc::new(|x: &[f64]| 1.0 / x[0]), 3),
+    ];
+    assert!(matches!(
+        compute(|_| 1.0, levels, ComputeMethod::Simpson),
+        Err(IntegraalError::BadParameters(_))
+    ));
+
+    // non-finite values of inner levels are reported
+    assert!(matches!(
+        compute(|x| 1.0 / x[1], triangle(3), ComputeMethod::Simpson),
+        Err(IntegraalError::NonFinite { .. })
+    ));
+
+    // functions should be closures of several variables
+    assert!(matches!(
+        Integraal::default()
+            .method(ComputeMethod::Simpson)
+            .function(FunctionDescriptor::Values(vec![1.0; 9]))
+            .region(RegionDescriptor::Iterated(triangle(3)))
+            .compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
@@ -1,12 +1,80 @@
 // ------ IMPORTS
 //
-use crate::{ComputeMethod, DomainDescriptor, FunctionDescriptor, Integraal, IntegraalError};
+use crate::{
+    ComputeMethod, Discontinuity, DiscontinuityDetection, DomainDescriptor, Evaluation,
+    FunctionDescriptor, Integraal, IntegraalError, NodeGenerator, Oscillator, Piece, Spacing,
+};
 
 // ------ MODULE DECLARATIONS
 
 // test incorrect usages of the struct
 mod incorrect;
 
+// test the type-state builder
+mod builder;
+
+// test non-finite value handling policies
+mod non_finite;
+
+// test domain-related features
+mod domains;
+
+// test piecewise domains
+mod piecewise;
+
+// test discontinuity detection
+mod detection;
+
+// test oscillatory integration methods
+mod oscillatory;
+
+// test singular integration methods
+#[cfg(feature = "singular")]
+mod singular;
+
+// test line integrals along curves
+mod curve;
+
+// test surface integrals over parametric surfaces
+mod surface;
+
+// test iterated integrals with variable limits
+mod iterated;
+
+// test integration of values over regular grids
+mod grids;
+
+// test integration of scattered values over triangulations
+mod scattered;
+
+// test integration over simplices & polygons
+#[cfg(feature = "simplex")]
+mod simplex;
+
+// test integration over spheres, discs & annuli
+#[cfg(feature = "polar")]
+mod polar;
+
+// test adaptive integration over hyperrectangles
+#[cfg(feature = "adaptive")]
+mod adaptive;
+
+// test sparse grid integration over hyperrectangles
+#[cfg(feature = "sparse")]
+mod sparse;
+
+// test periodic integration
+mod periodic;
+
+// test exactness of the rules on polynomials
+mod rules;
+
+// test repeated computations over the same descriptors
+mod reuse;
+
+// test summation strategies
+mod summation;
+
 // test are groups per module according to the integral & the computation method
 // test names follow this pattern:
 // <FunctionDescriptorEnum><DomainDescriptorEnum>
@@ -39,13 +107,11 @@ macro_rules! generate_sample_descriptors {
 }
 
 macro_rules! generate_missing {
-    ($a: ident, $b: ident) => {
+    ($a: ident, $b: ident, $missing: literal) => {
         let mut integral: Integraal<f64> = Integraal::default().$a($a).$b($b);
         assert_eq!(
             integral.compute(),
-            Err(IntegraalError::MissingParameters(
-                "one or more parameter is missing"
-            ))
+            Err(IntegraalError::MissingParameters(vec![$missing]))
         );
     };
 }
@@ -76,9 +142,10 @@ macro_rules! generate_test {
                 .domain(domaind)
                 .method(computem);
             let res = integraal.compute();
-            assert!(res.is_ok());
+            assert!(res.is_ok(), "{res:?}");
+            let res = res.unwrap();
             assert!(
-                almost_equal!($ft, res.unwrap(), $res, $tol),
+                almost_equal!($ft, res, $res, $tol),
                 "computed value: {res:?}\nexpected value: 2.0\ntolerance: {:?}",
                 $tol
             );
@@ -375,3 +442,32 @@ macro_rules! all_tests {
 }
 
 pub(crate) use {all_tests, almost_equal, generate_test};
+
+// compute the integral of a function of several variables over a region
+#[cfg(any(feature = "polar", feature = "simplex"))]
+pub(crate) fn compute_multi(
+    function: impl Fn(&[f64]) -> f64 + 'static,
+    region: crate::RegionDescriptor<f64>,
+    method: ComputeMethod,
+) -> Result<f64, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::MultiClosure(Box::new(function)))
+        .region(region)
+        .compute()
+}
+
+// monomial x_1^a_1 * ... * x_d^a_d
+#[cfg(any(feature = "polar", feature = "simplex"))]
+pub(crate) fn monomial(exponents: &[usize]) -> impl Fn(&[f64]) -> f64 {
+    let exponents: Vec<i32> = exponents
+        .iter()
+        .map(|a| i32::try_from(*a).unwrap())
+        .collect();
+    move |x| {
+        x.iter()
+            .zip(&exponents)
+            .map(|(xi, a)| xi.powi(*a))
+            .product()
+    }
+}
@@ -0,0 +1,274 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::NonFinitePolicy;
+
+// ------ TESTS
+
+// y = f(x) = 1 from 0 to 1, with a faulty sample at x = 0.5
+
+const N_STEP: usize = 11;
+const FAULTY: usize = 5;
+
+fn domain() -> DomainDescriptor<f64> {
+    DomainDescriptor::Uniform {
+        start: 0.,
+        step: 0.1,
+        n_step: N_STEP,
+    }
+}
+
+fn values(faulty: f64) -> FunctionDescriptor<f64> {
+    FunctionDescriptor::Values(
+        (0..N_STEP)
+            .map(|idx| if idx == FAULTY { faulty } else { 1.0 })
+            .collect(),
+    )
+}
+
+fn closure() -> FunctionDescriptor<f64> {
+    FunctionDescriptor::Closure(Box::new(|x: f64| {
+        if (x - 0.5).abs() < 1e-9 {
+            f64::NAN
+        } else {
+            1.0
+        }
+    }))
+}
+
+fn compute(
+    function: FunctionDescriptor<f64>,
+    policy: NonFinitePolicy,
+) -> Result<f64, IntegraalError> {
+    Integraal::default()
+        .domain(domain())
+        .function(function)
+        .method(ComputeMethod::Trapezoid)
+        .non_finite_policy(policy)
+        .compute()
+}
+
+#[test]
+fn error() {
+    let expected = Err(IntegraalError::NonFinite {
+        index: FAULTY,
+        abscissa: 0.5,
+    });
+    assert_eq!(compute(values(f64::NAN), NonFinitePolicy::Error), expected);
+    assert_eq!(
+        compute(values(f64::INFINITY), NonFinitePolicy::Error),
+        expected
+    );
+    assert_eq!(compute(closure(), NonFinitePolicy::Error), expected);
+}
+
+#[test]
+fn explicit_domain_error() {
+    let mut integral = Integraal::default()
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.1, 0.3, 0.7, 1.0]))
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| {
+            1.0 / (x - 0.3)
+        })))
+        .method(ComputeMethod::RectangleLeft);
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::NonFinite {
+            index: 2,
+            abscissa: 0.3,
+        })
+    );
+}
+
+#[test]
+fn skip() {
+    // the faulty sample is bridged by its neighbours
+    let res = compute(values(f64::NAN), NonFinitePolicy::Skip).unwrap();
+    assert!((res - 1.0).abs() < 1e-12);
+    let res = compute(closure(), NonFinitePolicy::Skip).unwrap();
+    assert!((res - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn replace_with_zero() {
+    // the faulty sample contributes to two trapezoids of width 0.1
+    let res = compute(values(f64::NAN), NonFinitePolicy::ReplaceWithZero).unwrap();
+    assert!((res - 0.9).abs() < 1e-12);
+    let res = compute(closure(), NonFinitePolicy::ReplaceWithZero).unwrap();
+    assert!((res - 0.9).abs() < 1e-12);
+}
+
+#[test]
+fn interpolate() {
+    let res = compute(values(f64::NEG_INFINITY), NonFinitePolicy::Interpolate).unwrap();
+    assert!((res - 1.0).abs() < 1e-12);
+    let res = compute(closure(), NonFinitePolicy::Interpolate).unwrap();
+    assert!((res - 1.0).abs() < 1e-12);
+
+    // linear values are recovered exactly, including at the boundaries
+    let mut integral = Integraal::default()
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.2, 0.3, 0.6, 1.0]))
+        .function(FunctionDescriptor::Values(vec![
+            f64::NAN,
+            0.2,
+            f64::NAN,
+            0.6,
+            f64::NAN,
+        ]))
+        .method(ComputeMethod::Trapezoid)
+        .non_finite_policy(NonFinitePolicy::Interpolate);
+    let res = integral.compute().unwrap();
+    // boundaries take their neighbour's value: 0.2 on [0, 0.2], 0.6 on [0.6, 1]; interior
+    // trapezoids average to 0.4 on [0.2, 0.6]
+    let expected = 0.2 * 0.2 + 0.4 * 0.4 + 0.6 * 0.4;
+    assert!((res - expected).abs() < 1e-12);
+}
+
+#[test]
+fn interpolate_all_faulty() {
+    let mut integral = Integraal::default()
+        .domain(DomainDescriptor::Explicit(vec![0.0, 0.5, 1.0]))
+        .function(FunctionDescriptor::Values(vec![f64::NAN; 3]))
+        .method(ComputeMethod::Trapezoid)
+        .non_finite_policy(NonFinitePolicy::Interpolate);
+    assert_eq!(
+        integral.compute(),
+        Err(IntegraalError::NonFinite {
+            index: 0,
+            abscissa: 0.0,
+        })
+    );
+}
+
+#[test]
+fn skip_too_few_samples() {
+    // two samples are left, while Simpson's rule requires three
+    let expected = Err(IntegraalError::TooFewSamples {
+        method: ComputeMethod::Simpson,
+        required: 3,
+        got: 2,
+    });
+    let mut integral = Integraal::default()
+        .domain(DomainDescriptor::Explicit(vec![0.0, 1.0, 2.0, 3.0]))
+        .function(FunctionDescriptor::Values(vec![
+            f64::NAN,
+            f64::NAN,
+            1.0,
+            2.0,
+        ]))
+        .method(ComputeMethod::Simpson)
+        .non_finite_policy(NonFinitePolicy::Skip);
+    assert_eq!(integral.compute(), expected);
+    let mut integral = Integraal::default()
+        .domain(DomainDescriptor::Explicit(vec![0.0, 1.0, 2.0, 3.0]))
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| {
+            if x < 1.5 {
+                f64::NAN
+            } else {
+                x - 1.0
+            }
+        })))
+        .method(ComputeMethod::Simpson)
+        .non_finite_policy(NonFinitePolicy::Skip);
+    assert_eq!(integral.compute(), expected);
+}
+
+#[cfg(feature = "romberg")]
+#[test]
+fn between_samples() {
+    // Romberg's method evaluates closures on dyadic subdivisions of the domain
+    let integral = |policy| {
+        Integraal::default()
+            .domain(domain())
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| {
+                if (x - 0.0625).abs() < 1e-12 {
+                    f64::NAN
+                } else {
+                    1.0
+                }
+            })))
+            .method(ComputeMethod::Romberg { max_steps: 5 })
+            .non_finite_policy(policy)
+    };
+    assert_eq!(
+        integral(NonFinitePolicy::Error).compute(),
+        Err(IntegraalError::NonFinite {
+            index: 0,
+            abscissa: 0.0625,
+        })
+    );
+    assert!(matches!(
+        integral(NonFinitePolicy::Skip).compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    assert!(matches!(
+        integral(NonFinitePolicy::Interpolate).compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
+
+#[cfg(feature = "open")]
+#[test]
+fn between_samples_midpoint() {
+    // the midpoint rule only evaluates closures between samples
+    let integral = |policy| {
+        Integraal::default()
+            .domain(DomainDescriptor::linspace(0.0, 1.0, N_STEP))
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| {
+                if (x - 0.05).abs() < 1e-12 {
+                    f64::NAN
+                } else {
+                    1.0
+                }
+            })))
+            .method(ComputeMethod::Midpoint)
+            .non_finite_policy(policy)
+    };
+    assert!(matches!(
+        integral(NonFinitePolicy::Error).compute(),
+        Err(IntegraalError::NonFinite { index: 0, abscissa }) if (abscissa - 0.05).abs() < 1e-12
+    ));
+    let res = integral(NonFinitePolicy::ReplaceWithZero)
+        .compute()
+        .unwrap();
+    assert!((res - 0.9).abs() < 1e-12);
+    assert!(matches!(
+        integral(NonFinitePolicy::Skip).compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    assert!(matches!(
+        integral(NonFinitePolicy::Interpolate).compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
+
+#[cfg(feature = "richardson")]
+#[test]
+fn between_samples_richardson() {
+    // step halving evaluates closures between the samples of the domain; the faulty value is
+    // reported rather than the lack of convergence it causes
+    let integral = |policy| {
+        Integraal::default()
+            .domain(domain())
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| {
+                if (x - 0.25).abs() < 1e-12 {
+                    f64::NAN
+                } else {
+                    x.exp()
+                }
+            })))
+            .method(ComputeMethod::Richardson {
+                base: crate::BaseRule::Trapezoid,
+                levels: 4,
+                tolerance: 1e-12,
+            })
+            .non_finite_policy(policy)
+    };
+    assert!(matches!(
+        integral(NonFinitePolicy::Error).compute(),
+        Err(IntegraalError::NonFinite { index: 2, abscissa }) if (abscissa - 0.25).abs() < 1e-12
+    ));
+    assert!(matches!(
+        integral(NonFinitePolicy::Skip).compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
@@ -0,0 +1,194 @@
+// ------ IMPORTS
+
+use super::*;
+
+// ------ TESTS
+
+const OMEGA: f64 = 200.0;
+
+// real & imaginary parts of the integral of e^x * e^{i * omega * x} over [0, 1]
+fn exp_oscillator(omega: f64) -> (f64, f64) {
+    let e = 1.0_f64.exp();
+    let (sin, cos) = omega.sin_cos();
+    let den = 1.0 + omega * omega;
+    (
+        (e * cos - 1.0 + omega * e * sin) / den,
+        (e * sin - omega * (e * cos - 1.0)) / den,
+    )
+}
+
+fn compute(
+    function: FunctionDescriptor<f64>,
+    domain: DomainDescriptor<f64>,
+    method: ComputeMethod,
+) -> Result<f64, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(function)
+        .domain(domain)
+        .compute()
+}
+
+#[test]
+fn oscillatory_descriptor() {
+    // generic methods integrate the real or imaginary part of the function
+    let (re, im) = exp_oscillator(OMEGA);
+    for (kind, expected) in [(Oscillator::Cos, re), (Oscillator::Sin, im)] {
+        let res = compute(
+            FunctionDescriptor::Oscillatory {
+                amplitude: Box::new(f64::exp),
+                phase: Box::new(|x| OMEGA * x),
+                phase_derivative: Box::new(|_| OMEGA),
+                kind,
+            },
+            DomainDescriptor::linspace(0.0, 1.0, 20_001),
+            ComputeMethod::Simpson,
+        )
+        .unwrap();
+        assert!(
+            (res - expected).abs() < 1e-10,
+            "{kind:?}: {res} vs {expected}"
+        );
+    }
+}
+
+#[cfg(feature = "filon")]
+#[test]
+fn filon() {
+    // the quadratic interpolation is exact for linear functions, whatever the frequency
+    let expected = OMEGA.sin() / OMEGA + (OMEGA.cos() - 1.0) / (OMEGA * OMEGA);
+    let res = compute(
+        FunctionDescriptor::Closure(Box::new(|x| x)),
+        DomainDescriptor::linspace(0.0, 1.0, 11),
+        ComputeMethod::Filon {
+            omega: OMEGA,
+            kind: Oscillator::Cos,
+        },
+    )
+    .unwrap();
+    assert!((res - expected).abs() < 1e-12, "{res} vs {expected}");
+
+    // far fewer samples than oscillations are required
+    let (re, im) = exp_oscillator(OMEGA);
+    let domain: DomainDescriptor<f64> = DomainDescriptor::linspace(0.0, 1.0, 101);
+    let vals: Vec<f64> = (0..101).map(|i| domain.abscissa(i).exp()).collect();
+    for (kind, expected) in [(Oscillator::Cos, re), (Oscillator::Sin, im)] {
+        let res = compute(
+            FunctionDescriptor::Values(vals.clone()),
+            domain.clone(),
+            ComputeMethod::Filon { omega: OMEGA, kind },
+        )
+        .unwrap();
+        assert!(
+            (res - expected).abs() < 1e-8,
+            "{kind:?}: {res} vs {expected}"
+        );
+    }
+
+    // low frequencies fall back to Simpson's rule
+    let res = compute(
+        FunctionDescriptor::Values(vals),
+        domain,
+        ComputeMethod::Filon {
+            omega: 0.0,
+            kind: Oscillator::Cos,
+        },
+    )
+    .unwrap();
+    assert!((res - (1.0_f64.exp() - 1.0)).abs() < 1e-9, "{res}");
+}
+
+#[cfg(feature = "filon")]
+#[test]
+fn filon_incorrect() {
+    let method = ComputeMethod::Filon {
+        omega: OMEGA,
+        kind: Oscillator::Sin,
+    };
+    assert_eq!(
+        compute(
+            FunctionDescriptor::Closure(Box::new(|x| x)),
+            DomainDescriptor::linspace(0.0, 1.0, 10),
+            method,
+        ),
+        Err(IntegraalError::BadParameters(String::from(
+            "domain should be divided into an even number of segments for Filon's method (segments: 9)"
+        )))
+    );
+    assert_eq!(
+        compute(
+            FunctionDescriptor::Closure(Box::new(|x| x)),
+            DomainDescriptor::Explicit(vec![0.0, 0.5, 1.0]),
+            method,
+        ),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "non-uniform domains",
+        })
+    );
+}
+
+#[cfg(feature = "levin")]
+#[test]
+fn levin() {
+    let (re, im) = exp_oscillator(OMEGA);
+    for (kind, expected) in [(Oscillator::Cos, re), (Oscillator::Sin, im)] {
+        let res = compute(
+            FunctionDescriptor::Oscillatory {
+                amplitude: Box::new(f64::exp),
+                phase: Box::new(|x| OMEGA * x),
+                phase_derivative: Box::new(|_| OMEGA),
+                kind,
+            },
+            DomainDescriptor::linspace(0.0, 1.0, 2),
+            ComputeMethod::Levin { n_nodes: 12 },
+        )
+        .unwrap();
+        assert!(
+            (res - expected).abs() < 1e-12,
+            "{kind:?}: {res} vs {expected}"
+        );
+    }
+
+    // non-linear phase, compared to a fine Simpson computation
+    let function = || FunctionDescriptor::Oscillatory {
+        amplitude: Box::new(|x: f64| 1.0 / (1.0 + x)),
+        phase: Box::new(|x| 50.0 * x * x),
+        phase_derivative: Box::new(|x| 100.0 * x),
+        kind: Oscillator::Sin,
+    };
+    let domain = DomainDescriptor::linspace(1.0, 2.0, 40_001);
+    let expected = compute(function(), domain.clone(), ComputeMethod::Simpson).unwrap();
+    let res = compute(function(), domain, ComputeMethod::Levin { n_nodes: 24 }).unwrap();
+    assert!((res - expected).abs() < 1e-10, "{res} vs {expected}");
+}
+
+#[cfg(feature = "levin")]
+#[test]
+fn levin_incorrect() {
+    let method = ComputeMethod::Levin { n_nodes: 12 };
+    assert_eq!(
+        compute(
+            FunctionDescriptor::Closure(Box::new(|x| x)),
+            DomainDescriptor::linspace(0.0, 1.0, 2),
+            method,
+        ),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "non-oscillatory function descriptors",
+        })
+    );
+    assert!(matches!(
+        compute(
+            FunctionDescriptor::Oscillatory {
+                amplitude: Box::new(f64::exp),
+                phase: Box::new(|x| OMEGA * x),
+                phase_derivative: Box::new(|_| OMEGA),
+                kind: Oscillator::Cos,
+            },
+            DomainDescriptor::linspace(0.0, 1.0, 2),
+            ComputeMethod::Levin { n_nodes: 1 },
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+}
@@ -0,0 +1,110 @@
+// ------ IMPORTS
+
+use super::*;
+use std::f64::consts::PI;
+
+// ------ TESTS
+
+// integral of e^sin(x) over a period is 2 * pi * I0(1)
+const EXPECTED: f64 = 2.0 * PI * 1.266_065_877_752_008_4;
+
+fn period(n_step: usize) -> DomainDescriptor<f64> {
+    DomainDescriptor::Uniform {
+        start: 0.0,
+        step: 2.0 * PI / n_step as f64,
+        n_step,
+    }
+}
+
+fn evaluate(
+    function: FunctionDescriptor<f64>,
+    domain: DomainDescriptor<f64>,
+    method: ComputeMethod,
+) -> Result<Evaluation<f64>, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(function)
+        .domain(domain)
+        .periodic(true)
+        .evaluate_detailed()
+}
+
+#[test]
+fn spectral_convergence() {
+    let closure = || FunctionDescriptor::Closure(Box::new(|x: f64| x.sin().exp()));
+    let res = evaluate(closure(), period(32), ComputeMethod::Trapezoid).unwrap();
+    assert!((res.value - EXPECTED).abs() < 1e-14, "{}", res.value);
+    assert!(res.error.unwrap() < 1e-12, "{:?}", res.error);
+
+    // the estimate is computed from half the samples, so it is pessimistic
+    let res = evaluate(closure(), period(8), ComputeMethod::Trapezoid).unwrap();
+    let error = (res.value - EXPECTED).abs();
+    assert!(error < 1e-5, "{error}");
+    assert!(res.error.unwrap() > error, "{:?} vs {error}", res.error);
+
+    // no estimate for odd numbers of samples
+    let res = evaluate(closure(), period(15), ComputeMethod::Trapezoid).unwrap();
+    assert!((res.value - EXPECTED).abs() < 1e-12, "{}", res.value);
+    assert_eq!(res.error, None);
+}
+
+#[test]
+fn rules_and_descriptors() {
+    let domain = period(24);
+    let vals: Vec<f64> = (0..24).map(|i| domain.abscissa(i).sin().exp()).collect();
+    for method in [
+        ComputeMethod::RectangleLeft,
+        ComputeMethod::RectangleRight,
+        ComputeMethod::Trapezoid,
+    ] {
+        let res = evaluate(
+            FunctionDescriptor::Values(vals.clone()),
+            domain.clone(),
+            method,
+        )
+        .unwrap();
+        assert!(
+            (res.value - EXPECTED).abs() < 1e-13,
+            "{method:?}: {}",
+            res.value
+        );
+    }
+
+    // the period is shifted by one step, with the same samples
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Values(vals.into_iter().rev().collect()))
+        .domain(DomainDescriptor::Uniform {
+            start: 2.0 * PI * 23.0 / 24.0,
+            step: -2.0 * PI / 24.0,
+            n_step: 24,
+        })
+        .periodic(true)
+        .allow_descending(true)
+        .evaluate_detailed()
+        .unwrap();
+    assert!((res.value + EXPECTED).abs() < 1e-13, "{}", res.value);
+}
+
+#[test]
+fn periodic_incorrect() {
+    let closure = || FunctionDescriptor::Closure(Box::new(|x: f64| x.sin().exp()));
+    assert_eq!(
+        evaluate(closure(), period(32), ComputeMethod::Simpson),
+        Err(IntegraalError::Unimplemented {
+            method: ComputeMethod::Simpson,
+            context: "periodic functions",
+        })
+    );
+    assert_eq!(
+        evaluate(
+            closure(),
+            DomainDescriptor::Explicit(vec![0.0, 1.0, 3.0]),
+            ComputeMethod::Trapezoid
+        ),
+        Err(IntegraalError::Unimplemented {
+            method: ComputeMethod::Trapezoid,
+            context: "periodic functions over non-uniform domains",
+        })
+    );
+}
@@ -0,0 +1,162 @@
+// ------ IMPORTS
+
+use super::*;
+
+// ------ TESTS
+
+// y = f(x) = | 0 from 0 to 1
+//            | 1 from 1 to 1.5
+//            | 0 from 1.5 to 2
+// i.e. integral C, which is exactly integrated if the jumps are breakpoints
+
+fn step_function(x: f64) -> f64 {
+    if x < 1.0 {
+        0.0
+    } else if x < 1.5 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn pieces() -> Vec<Piece<f64>> {
+    vec![
+        Piece::new(DomainDescriptor::linspace(0.0, 1.0, 101)),
+        Piece::new(DomainDescriptor::linspace(1.0, 1.5, 51)),
+        Piece::new(DomainDescriptor::Explicit(
+            (0..=50).map(|i| 1.5 + f64::from(i) * 0.01).collect(),
+        )),
+    ]
+}
+
+#[test]
+fn closure() {
+    for method in [
+        ComputeMethod::RectangleLeft,
+        ComputeMethod::RectangleRight,
+        ComputeMethod::Trapezoid,
+        ComputeMethod::Simpson,
+    ] {
+        let res = Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::Closure(Box::new(step_function)))
+            .domain(DomainDescriptor::Piecewise(pieces()))
+            .compute()
+            .unwrap();
+        assert!((res - 0.5).abs() < 1e-12, "{method:?}: {res}");
+    }
+
+    // a single grid straddling the jumps is less accurate
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Closure(Box::new(step_function)))
+        .domain(DomainDescriptor::linspace(0.0, 2.0, 200))
+        .compute()
+        .unwrap();
+    assert!((res - 0.5).abs() > 1e-3, "{res}");
+}
+
+#[test]
+fn values() {
+    // breakpoints appear once per piece, with one-sided values
+    let domain = DomainDescriptor::Piecewise(pieces());
+    let vals: Vec<f64> = [(101, 0.0), (51, 1.0), (51, 0.0)]
+        .into_iter()
+        .flat_map(|(n, v)| std::iter::repeat_n(v, n))
+        .collect();
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Values(vals))
+        .domain(domain)
+        .compute()
+        .unwrap();
+    assert!((res - 0.5).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn method_override() {
+    // the quadratic piece is integrated exactly using Simpson's rule
+    let domain = DomainDescriptor::Piecewise(vec![
+        Piece::new(DomainDescriptor::linspace(-1.0, 0.0, 3)),
+        Piece::new(DomainDescriptor::linspace(0.0, 1.0, 3)).method(ComputeMethod::Simpson),
+    ]);
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Closure(Box::new(|x: f64| {
+            if x < 0.0 {
+                1.0
+            } else {
+                x * x
+            }
+        })))
+        .domain(domain)
+        .compute()
+        .unwrap();
+    assert!((res - 4.0 / 3.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn bad_pieces() {
+    let integral = |domain| {
+        Integraal::default()
+            .method(ComputeMethod::Trapezoid)
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+            .domain(domain)
+            .compute()
+    };
+
+    // gap between pieces
+    let res = integral(DomainDescriptor::Piecewise(vec![
+        Piece::new(DomainDescriptor::linspace(0.0, 1.0, 11)),
+        Piece::new(DomainDescriptor::linspace(1.1, 2.0, 11)),
+    ]));
+    assert_eq!(
+        res,
+        Err(IntegraalError::BadParameters(String::from(
+            "pieces should be joined at breakpoints (piece 0 ends at 1.0, piece 1 starts at 1.1)"
+        )))
+    );
+
+    // nested & empty piecewise domains
+    let res = integral(DomainDescriptor::Piecewise(vec![Piece::new(
+        DomainDescriptor::Piecewise(Vec::new()),
+    )]));
+    assert!(matches!(res, Err(IntegraalError::BadParameters(_))));
+    let res = integral(DomainDescriptor::Piecewise(Vec::new()));
+    assert!(matches!(res, Err(IntegraalError::BadParameters(_))));
+
+    // too few samples in a piece
+    let res = integral(DomainDescriptor::Piecewise(vec![
+        Piece::new(DomainDescriptor::linspace(0.0, 1.0, 11)),
+        Piece::new(DomainDescriptor::linspace(1.0, 2.0, 2)).method(ComputeMethod::Simpson),
+    ]));
+    assert!(matches!(res, Err(IntegraalError::TooFewSamples { .. })));
+}
+
+#[test]
+fn non_finite_index() {
+    // indices of faulty samples refer to the whole domain
+    let domain = DomainDescriptor::Piecewise(vec![
+        Piece::new(DomainDescriptor::linspace(0.0, 1.0, 3)),
+        Piece::new(DomainDescriptor::linspace(1.0, 2.0, 3)),
+    ]);
+    let res = Integraal::default()
+        .method(ComputeMethod::Trapezoid)
+        .function(FunctionDescriptor::Values(vec![
+            0.0,
+            1.0,
+            2.0,
+            2.0,
+            f64::NAN,
+            4.0,
+        ]))
+        .domain(domain)
+        .compute();
+    assert_eq!(
+        res,
+        Err(IntegraalError::NonFinite {
+            index: 4,
+            abscissa: 1.5
+        })
+    );
+}
@@ -0,0 +1,158 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::RegionDescriptor;
+use std::f64::consts::PI;
+
+// ------ TESTS
+
+fn unit_sphere() -> RegionDescriptor<f64> {
+    RegionDescriptor::Sphere {
+        center: [0.0; 3],
+        radius: 1.0,
+    }
+}
+
+/// Integral of `x^a * y^b * z^c` over the unit sphere.
+fn sphere_integral([a, b, c]: [usize; 3]) -> f64 {
+    let double_factorial = |n: usize| (1..=n).rev().step_by(2).map(|i| i as f64).product::<f64>();
+    if a % 2 == 1 || b % 2 == 1 || c % 2 == 1 {
+        return 0.0;
+    }
+    4.0 * PI
+        * double_factorial(a.saturating_sub(1))
+        * double_factorial(b.saturating_sub(1))
+        * double_factorial(c.saturating_sub(1))
+        / double_factorial(a + b + c + 1)
+}
+
+#[test]
+fn lebedev() {
+    for (n_points, degree) in [(6, 3), (14, 5), (26, 7), (38, 9), (50, 11)] {
+        for a in 0..=degree {
+            for b in 0..=degree - a {
+                let exponents = [a, b, degree - a - b];
+                let expected = sphere_integral(exponents);
+                let res = compute_multi(
+                    monomial(&exponents),
+                    unit_sphere(),
+                    ComputeMethod::Lebedev { n_points },
+                )
+                .unwrap();
+                assert!(
+                    (res - expected).abs() < 1e-12,
+                    "{n_points}, {exponents:?}: {res} vs {expected}"
+                );
+            }
+        }
+    }
+
+    // the area is 16 * pi, and the mean of x over the sphere is its center's
+    let sphere = RegionDescriptor::Sphere {
+        center: [1.0, 0.0, -3.0],
+        radius: 2.0,
+    };
+    let res = compute_multi(|p| p[0], sphere, ComputeMethod::Lebedev { n_points: 6 }).unwrap();
+    assert!((res - 16.0 * PI).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn spherical_product() {
+    let method = ComputeMethod::SphericalProduct {
+        n_polar: 4,
+        n_azimuthal: 8,
+    };
+    for exponents in [[2, 2, 2], [4, 0, 2], [0, 6, 0], [3, 1, 0], [1, 2, 4]] {
+        let expected = sphere_integral(exponents);
+        let res = compute_multi(monomial(&exponents), unit_sphere(), method).unwrap();
+        assert!(
+            (res - expected).abs() < 1e-12,
+            "{exponents:?}: {res} vs {expected}"
+        );
+    }
+
+    // smooth non-polynomial function; integral of e^z is 4 * pi * sinh(1)
+    let method = ComputeMethod::SphericalProduct {
+        n_polar: 10,
+        n_azimuthal: 1,
+    };
+    let res = compute_multi(|p| p[2].exp(), unit_sphere(), method).unwrap();
+    assert!((res - 4.0 * PI * 1.0_f64.sinh()).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn polar_gauss() {
+    let method = ComputeMethod::PolarGauss {
+        n_radial: 3,
+        n_angular: 6,
+    };
+    let disc = RegionDescriptor::Disc {
+        center: [1.0, 2.0],
+        radius: 1.0,
+    };
+    let res = compute_multi(|_| 1.0, disc, method).unwrap();
+    assert!((res - PI).abs() < 1e-12, "{res}");
+
+    // integral of x^2 * y^2 over the unit disc
+    let disc = RegionDescriptor::Disc {
+        center: [0.0, 0.0],
+        radius: 1.0,
+    };
+    let res = compute_multi(|p| p[0] * p[0] * p[1] * p[1], disc, method).unwrap();
+    assert!((res - PI / 24.0).abs() < 1e-12, "{res}");
+
+    // integral of r^2 over the annulus between radii 1 and 2
+    let annulus = RegionDescriptor::Annulus {
+        center: [0.0, 0.0],
+        inner_radius: 1.0,
+        outer_radius: 2.0,
+    };
+    let res = compute_multi(|p| p[0] * p[0] + p[1] * p[1], annulus, method).unwrap();
+    assert!((res - 7.5 * PI).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn polar_incorrect() {
+    assert!(matches!(
+        compute_multi(
+            |_| 1.0,
+            unit_sphere(),
+            ComputeMethod::Lebedev { n_points: 7 }
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        compute_multi(
+            |_| 1.0,
+            unit_sphere(),
+            ComputeMethod::SphericalProduct {
+                n_polar: 0,
+                n_azimuthal: 4
+            }
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    let method = ComputeMethod::PolarGauss {
+        n_radial: 2,
+        n_angular: 4,
+    };
+    assert!(matches!(
+        compute_multi(
+            |_| 1.0,
+            RegionDescriptor::Annulus {
+                center: [0.0, 0.0],
+                inner_radius: 2.0,
+                outer_radius: 1.0,
+            },
+            method
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert_eq!(
+        compute_multi(|_| 1.0, unit_sphere(), method),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "spheres",
+        })
+    );
+}
@@ -0,0 +1,94 @@
+// ------ IMPORTS
+
+use super::*;
+use std::{cell::Cell, rc::Rc};
+
+// ------ TESTS
+
+// y = f(x) = x^2 from 0 to 1, with an evaluation counter
+
+fn counted_integral(counter: &Rc<Cell<usize>>) -> Integraal<f64> {
+    let counter = counter.clone();
+    Integraal::default()
+        .domain(DomainDescriptor::Uniform {
+            start: 0.0,
+            step: 0.01,
+            n_step: 101,
+        })
+        .function(FunctionDescriptor::Closure(Box::new(move |x: f64| {
+            counter.set(counter.get() + 1);
+            x * x
+        })))
+}
+
+#[test]
+fn evaluate_keeps_function() {
+    let counter = Rc::new(Cell::new(0));
+    let mut integral = counted_integral(&counter).method(ComputeMethod::Simpson);
+
+    let first = integral.evaluate().unwrap();
+    let second = integral.evaluate().unwrap();
+    assert_eq!(first.to_bits(), second.to_bits());
+    assert!((first - 1.0 / 3.0).abs() < 1e-12);
+
+    // compute still clears the function descriptor
+    assert_eq!(integral.compute(), Ok(first));
+    assert_eq!(
+        integral.evaluate(),
+        Err(IntegraalError::MissingParameters(vec!["function"]))
+    );
+}
+
+#[test]
+fn compute_all() {
+    let methods = [
+        ComputeMethod::RectangleLeft,
+        ComputeMethod::RectangleRight,
+        ComputeMethod::Trapezoid,
+        ComputeMethod::Simpson,
+    ];
+    let counter = Rc::new(Cell::new(0));
+    let integral = counted_integral(&counter);
+    let comparison = integral.compute_all(&methods).unwrap();
+
+    // the closure is evaluated once per domain sample
+    assert_eq!(counter.get(), 101);
+
+    // results are identical to separate computations
+    assert_eq!(comparison.results.len(), methods.len());
+    for method in methods {
+        let res = counted_integral(&counter).method(method).evaluate();
+        assert_eq!(comparison.get(&method), Some(&res));
+    }
+    assert!(comparison.spread().unwrap() < 0.011);
+    assert_eq!(comparison.to_string().lines().count(), methods.len());
+}
+
+#[test]
+fn compute_all_errors() {
+    let integral: Integraal<f64> = Integraal::default().domain(DomainDescriptor::Uniform {
+        start: 0.0,
+        step: 0.5,
+        n_step: 2,
+    });
+    assert_eq!(
+        integral.compute_all(&[ComputeMethod::Trapezoid]),
+        Err(IntegraalError::MissingParameters(vec!["function"]))
+    );
+
+    // failing methods do not prevent other computations
+    let integral = integral.function(FunctionDescriptor::Values(vec![1.0, 1.0]));
+    let comparison = integral
+        .compute_all(&[ComputeMethod::Simpson, ComputeMethod::Trapezoid])
+        .unwrap();
+    assert_eq!(
+        comparison.get(&ComputeMethod::Simpson),
+        Some(&Err(IntegraalError::TooFewSamples {
+            method: ComputeMethod::Simpson,
+            required: 3,
+            got: 2
+        }))
+    );
+    assert_eq!(comparison.get(&ComputeMethod::Trapezoid), Some(&Ok(0.5)));
+    assert_eq!(comparison.spread(), Some(0.0));
+}
@@ -0,0 +1,296 @@
+// ------ IMPORTS
+
+use super::*;
+#[cfg(feature = "richardson")]
+use crate::BaseRule;
+
+// ------ TESTS
+
+// rules are checked against polynomials they should integrate exactly
+
+fn compute(
+    function: FunctionDescriptor<f64>,
+    domain: DomainDescriptor<f64>,
+    method: ComputeMethod,
+) -> f64 {
+    Integraal::default()
+        .function(function)
+        .domain(domain)
+        .method(method)
+        .compute()
+        .unwrap()
+}
+
+fn cubic(x: f64) -> f64 {
+    x.powi(3) - 2.0 * x + 1.0
+}
+
+// integral of the cubic over [0, 2]
+const CUBIC_RES: f64 = 2.0;
+
+// irregular samples over [0, 2]
+fn irregular(n_seg: usize) -> Vec<f64> {
+    (0..=n_seg)
+        .map(|i| {
+            let t = i as f64 / n_seg as f64;
+            t * (1.0 + t)
+        })
+        .collect()
+}
+
+#[test]
+fn simpson_uniform() {
+    // even & odd number of segments
+    for n_step in [3, 4, 5, 10, 11] {
+        let domain = DomainDescriptor::Uniform {
+            start: 0.0,
+            step: 2.0 / (n_step - 1) as f64,
+            n_step,
+        };
+        let vals = (0..n_step).map(|i| cubic(domain.abscissa(i))).collect();
+        let res = compute(
+            FunctionDescriptor::Closure(Box::new(cubic)),
+            domain.clone(),
+            ComputeMethod::Simpson,
+        );
+        assert!((res - CUBIC_RES).abs() < 1e-12, "{n_step}: {res}");
+        let res = compute(
+            FunctionDescriptor::Values(vals),
+            domain,
+            ComputeMethod::Simpson,
+        );
+        assert!((res - CUBIC_RES).abs() < 1e-12, "{n_step}: {res}");
+    }
+}
+
+#[test]
+fn simpson_explicit() {
+    // the irregular formula is exact for quadratics, with both even & odd number of segments
+    let quadratic = |x: f64| 3.0 * x * x - x;
+    for n_seg in [2, 3, 4, 7, 10] {
+        let args = irregular(n_seg);
+        let vals = args.iter().map(|x| quadratic(*x)).collect();
+        let res = compute(
+            FunctionDescriptor::Closure(Box::new(quadratic)),
+            DomainDescriptor::Explicit(args.clone()),
+            ComputeMethod::Simpson,
+        );
+        assert!((res - 6.0).abs() < 1e-12, "{n_seg}: {res}");
+        let res = compute(
+            FunctionDescriptor::Values(vals),
+            DomainDescriptor::Explicit(args),
+            ComputeMethod::Simpson,
+        );
+        assert!((res - 6.0).abs() < 1e-12, "{n_seg}: {res}");
+    }
+}
+
+#[cfg(feature = "boole")]
+#[test]
+fn boole() {
+    // Boole's rule is exact for polynomials up to degree 5
+    let quintic = |x: f64| x.powi(5) - x.powi(4) + 1.0;
+    let expected = 64.0 / 6.0 - 32.0 / 5.0 + 2.0;
+    let domain = DomainDescriptor::Uniform {
+        start: 0.0,
+        step: 0.25,
+        n_step: 9,
+    };
+    let res = compute(
+        FunctionDescriptor::Closure(Box::new(quintic)),
+        domain.clone(),
+        ComputeMethod::Boole { force: false },
+    );
+    assert!((res - expected).abs() < 1e-12, "{res}");
+
+    // forcing the computation truncates the domain to [0, 2]
+    let domain = DomainDescriptor::Uniform {
+        start: 0.0,
+        step: 0.25,
+        n_step: 11,
+    };
+    let res = compute(
+        FunctionDescriptor::Values((0..11).map(|i| quintic(domain.abscissa(i))).collect()),
+        domain,
+        ComputeMethod::Boole { force: true },
+    );
+    assert!((res - expected).abs() < 1e-12, "{res}");
+}
+
+#[cfg(feature = "romberg")]
+#[test]
+fn romberg() {
+    // with k levels, Romberg's method is exact for polynomials up to degree 2k - 1
+    let quintic = |x: f64| x.powi(5) - x.powi(4) + 1.0;
+    let expected = 64.0 / 6.0 - 32.0 / 5.0 + 2.0;
+    let res = compute(
+        FunctionDescriptor::Closure(Box::new(quintic)),
+        DomainDescriptor::Uniform {
+            start: 0.0,
+            step: 0.1,
+            n_step: 21,
+        },
+        ComputeMethod::Romberg { max_steps: 3 },
+    );
+    assert!((res - expected).abs() < 1e-12, "{res}");
+
+    // the number of levels is limited by the number of times segments can be halved
+    for (n_seg, max_steps) in [(4, 3), (8, 3), (12, 10), (16, 10)] {
+        let domain = DomainDescriptor::Uniform {
+            start: 0.0,
+            step: 2.0 / n_seg as f64,
+            n_step: n_seg + 1,
+        };
+        let res = compute(
+            FunctionDescriptor::Values((0..=n_seg).map(|i| quintic(domain.abscissa(i))).collect()),
+            domain,
+            ComputeMethod::Romberg { max_steps },
+        );
+        assert!((res - expected).abs() < 1e-12, "{n_seg}: {res}");
+    }
+}
+
+#[test]
+fn uniform_endpoint() {
+    // the last abscissa of a uniform domain is computed without accumulating the step
+    let domain: DomainDescriptor<f64> = DomainDescriptor::Uniform {
+        start: 0.0,
+        step: 0.1,
+        n_step: 11,
+    };
+    assert_eq!(domain.abscissa(10).to_bits(), 1.0_f64.to_bits());
+    let res = compute(
+        FunctionDescriptor::Closure(Box::new(|x| x)),
+        domain,
+        ComputeMethod::Trapezoid,
+    );
+    assert!((res - 0.5).abs() < 1e-15, "{res}");
+}
+
+#[cfg(feature = "gregory")]
+#[test]
+fn gregory() {
+    // with k correction terms, Gregory's method is exact for polynomials up to degree k
+    for order in 1..=8_usize {
+        let degree = order as f64;
+        let expected = 2.0_f64.powf(degree + 1.0) / (degree + 1.0);
+        for n_seg in [order, 10, 17] {
+            let domain: DomainDescriptor<f64> = DomainDescriptor::linspace(0.0, 2.0, n_seg + 1);
+            let vals = (0..=n_seg)
+                .map(|i| domain.abscissa(i).powf(degree))
+                .collect();
+            let res = compute(
+                FunctionDescriptor::Values(vals),
+                domain,
+                ComputeMethod::Gregory { order },
+            );
+            assert!(
+                (res - expected).abs() < 1e-10,
+                "{order}, {n_seg}: {res} vs {expected}"
+            );
+        }
+    }
+
+    // high accuracy with sample counts that do not fit other high-order rules
+    let res = compute(
+        FunctionDescriptor::Closure(Box::new(f64::exp)),
+        DomainDescriptor::linspace(0.0, 1.0, 24),
+        ComputeMethod::Gregory { order: 6 },
+    );
+    assert!((res - (1.0_f64.exp() - 1.0)).abs() < 1e-12, "{res}");
+}
+
+#[cfg(feature = "richardson")]
+#[test]
+fn richardson() {
+    let expected = 1.0_f64.exp() - 1.0;
+    for base in [BaseRule::Midpoint, BaseRule::Trapezoid, BaseRule::Simpson] {
+        let res = compute(
+            FunctionDescriptor::Closure(Box::new(f64::exp)),
+            DomainDescriptor::linspace(0.0, 1.0, 2),
+            ComputeMethod::Richardson {
+                base,
+                levels: 10,
+                tolerance: 1e-13,
+            },
+        );
+        assert!((res - expected).abs() < 1e-12, "{base:?}: {res}");
+    }
+
+    // the tolerance cannot be met with two trapezoid estimates, i.e. 3 evaluations
+    let res = Integraal::default()
+        .function(FunctionDescriptor::Closure(Box::new(f64::exp)))
+        .domain(DomainDescriptor::linspace(0.0, 1.0, 2))
+        .method(ComputeMethod::Richardson {
+            base: BaseRule::Trapezoid,
+            levels: 2,
+            tolerance: 1e-13,
+        })
+        .compute();
+    assert!(
+        matches!(
+            res,
+            Err(IntegraalError::NotConverged { evaluations: 3, .. })
+        ),
+        "{res:?}"
+    );
+
+    // the step of the midpoint rule is divided by 3, reusing the first midpoint
+    let res = Integraal::default()
+        .function(FunctionDescriptor::Closure(Box::new(f64::exp)))
+        .domain(DomainDescriptor::linspace(0.0, 1.0, 2))
+        .method(ComputeMethod::Richardson {
+            base: BaseRule::Midpoint,
+            levels: 2,
+            tolerance: 1e-13,
+        })
+        .compute();
+    assert!(
+        matches!(
+            res,
+            Err(IntegraalError::NotConverged { evaluations: 3, .. })
+        ),
+        "{res:?}"
+    );
+}
+
+#[cfg(feature = "open")]
+#[test]
+fn open_newton_cotes() {
+    // (number of points, maximum degree of exactness)
+    for (n_points, degree) in [(1, 1), (2, 1), (3, 3), (4, 3), (5, 5)] {
+        let degree = f64::from(degree);
+        let expected = 2.0_f64.powf(degree + 1.0) / (degree + 1.0);
+        for domain in [
+            DomainDescriptor::linspace(0.0, 2.0, 5),
+            DomainDescriptor::Explicit(irregular(6)),
+        ] {
+            let res = compute(
+                FunctionDescriptor::Closure(Box::new(move |x: f64| x.powf(degree))),
+                domain,
+                ComputeMethod::OpenNewtonCotes { n_points },
+            );
+            assert!((res - expected).abs() < 1e-12, "{n_points}: {res}");
+        }
+    }
+
+    // the function is never evaluated at the nodes of the domain
+    let singular = |x: f64| {
+        if x > 0.0 && x < 1.0 {
+            x
+        } else {
+            f64::NAN
+        }
+    };
+    for method in [
+        ComputeMethod::Midpoint,
+        ComputeMethod::OpenNewtonCotes { n_points: 3 },
+    ] {
+        let res = compute(
+            FunctionDescriptor::Closure(Box::new(singular)),
+            DomainDescriptor::linspace(0.0, 1.0, 2),
+            method,
+        );
+        assert!((res - 0.5).abs() < 1e-12, "{method:?}: {res}");
+    }
+}
@@ -0,0 +1,185 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{NonFinitePolicy, RegionDescriptor};
+
+// ------ TESTS
+
+fn compute(
+    points: Vec<[f64; 2]>,
+    clip: Option<Vec<[f64; 2]>>,
+    f: impl Fn(f64, f64) -> f64,
+    method: ComputeMethod,
+) -> Result<f64, IntegraalError> {
+    let vals = points.iter().map(|p| f(p[0], p[1])).collect();
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Values(vals))
+        .region(RegionDescriptor::Scattered { points, clip })
+        .compute()
+}
+
+/// Points of a `n x n` lattice over the unit square, jittered except along the boundary.
+fn lattice(n: usize, jitter: f64) -> Vec<[f64; 2]> {
+    let h = 1.0 / (n - 1) as f64;
+    (0..n)
+        .flat_map(|i| {
+            (0..n).map(move |j| {
+                let interior = i > 0 && j > 0 && i < n - 1 && j < n - 1;
+                let shift = if interior {
+                    jitter * h * ((7 * i + 3 * j) as f64).sin()
+                } else {
+                    0.0
+                };
+                [i as f64 * h + shift, j as f64 * h - shift]
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn scattered_linear() {
+    // the interpolant of a linear function is exact, whatever the triangulation
+    let f = |x: f64, y: f64| 1.0 + 2.0 * x + 3.0 * y;
+    for points in [
+        vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.3, 0.6]],
+        lattice(6, 0.0),
+        lattice(9, 0.3),
+    ] {
+        let res = compute(points, None, f, ComputeMethod::Trapezoid).unwrap();
+        assert!((res - 3.5).abs() < 1e-12, "{res}");
+    }
+    // points given in arbitrary order, covering a triangle with collinear points on its edges
+    let points = vec![
+        [0.5, 0.0],
+        [0.0, 0.0],
+        [0.25, 0.0],
+        [0.0, 1.0],
+        [1.0, 0.0],
+        [0.1, 0.1],
+    ];
+    let res = compute(points, None, |_, _| 2.0, ComputeMethod::Trapezoid).unwrap();
+    assert!((res - 1.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn scattered_smooth() {
+    // x^2 + y^2 over the unit square; the error decreases with the spacing
+    let f = |x: f64, y: f64| x * x + y * y;
+    let errors: Vec<f64> = [5, 9, 17]
+        .into_iter()
+        .map(|n| {
+            (compute(lattice(n, 0.2), None, f, ComputeMethod::Trapezoid).unwrap() - 2.0 / 3.0).abs()
+        })
+        .collect();
+    assert!(errors[0] < 5e-2, "{errors:?}");
+    assert!(errors[1] < errors[0] && errors[2] < errors[1], "{errors:?}");
+}
+
+#[test]
+fn scattered_clip() {
+    let method = ComputeMethod::Trapezoid;
+    let f = |x: f64, _: f64| x;
+    // L-shaped (non-convex) polygon, clockwise
+    let l_shape = vec![
+        [0.0, 0.0],
+        [0.0, 1.0],
+        [0.5, 1.0],
+        [0.5, 0.5],
+        [1.0, 0.5],
+        [1.0, 0.0],
+    ];
+    let res = compute(lattice(7, 0.3), Some(l_shape), f, method).unwrap();
+    assert!((res - 0.3125).abs() < 1e-12, "{res}");
+    // parts of the polygon outside of the convex hull are ignored
+    let large = vec![[-1.0, -1.0], [2.0, -1.0], [2.0, 2.0], [-1.0, 2.0]];
+    let res = compute(lattice(4, 0.0), Some(large), f, method).unwrap();
+    assert!((res - 0.5).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn scattered_non_finite() {
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.5, 0.5]];
+    let vals = vec![1.0, 1.0, 1.0, 1.0, f64::NAN];
+    let integral = |policy| {
+        Integraal::default()
+            .method(ComputeMethod::Trapezoid)
+            .function(FunctionDescriptor::Values(vals.clone()))
+            .region(RegionDescriptor::Scattered {
+                points: points.clone(),
+                clip: None,
+            })
+            .non_finite_policy(policy)
+            .compute()
+    };
+    assert_eq!(
+        integral(NonFinitePolicy::Error),
+        Err(IntegraalError::NonFinite {
+            index: 4,
+            abscissa: 0.5,
+        })
+    );
+    // the point is removed, and the square is triangulated using its corners only
+    let res = integral(NonFinitePolicy::Skip).unwrap();
+    assert!((res - 1.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn scattered_incorrect() {
+    let method = ComputeMethod::Trapezoid;
+    let f = |_: f64, _: f64| 1.0;
+    assert!(matches!(
+        compute(vec![[0.0, 0.0], [1.0, 0.0]], None, f, method),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    // duplicate points
+    assert!(matches!(
+        compute(
+            vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 0.0]],
+            None,
+            f,
+            method
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    // collinear points
+    assert!(matches!(
+        compute(vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]], None, f, method),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        compute(
+            vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            Some(vec![[0.0, 0.0], [1.0, 0.0]]),
+            f,
+            method
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert_eq!(
+        compute(
+            vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            None,
+            f,
+            ComputeMethod::Simpson
+        ),
+        Err(IntegraalError::Unimplemented {
+            method: ComputeMethod::Simpson,
+            context: "scattered points",
+        })
+    );
+    assert_eq!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::Values(vec![1.0; 2]))
+            .region(RegionDescriptor::Scattered {
+                points: vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+                clip: None,
+            })
+            .compute(),
+        Err(IntegraalError::LengthMismatch {
+            domain: 3,
+            function: 2,
+        })
+    );
+}
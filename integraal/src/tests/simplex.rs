@@ -0,0 +1,206 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{NonFinitePolicy, RegionDescriptor};
+
+// ------ TESTS
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).map(|i| i as f64).product()
+}
+
+/// Reference simplex of dimension `dim`, i.e. the origin & the unit vectors.
+fn reference(dim: usize) -> RegionDescriptor<f64> {
+    RegionDescriptor::Simplex(
+        (0..=dim)
+            .map(|i| {
+                (0..dim)
+                    .map(|k| if k + 1 == i { 1.0 } else { 0.0 })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Integral of `x_1^a_1 * ... * x_d^a_d` over the reference simplex.
+fn simplex_integral(exponents: &[usize]) -> f64 {
+    let total: usize = exponents.iter().sum();
+    exponents.iter().map(|a| factorial(*a)).product::<f64>() / factorial(total + exponents.len())
+}
+
+#[test]
+fn dunavant() {
+    for degree in 1..=8 {
+        for a in 0..=degree {
+            let exponents = vec![a, degree - a];
+            let expected = simplex_integral(&exponents);
+            let res = compute_multi(
+                monomial(&exponents),
+                reference(2),
+                ComputeMethod::Dunavant { degree },
+            )
+            .unwrap();
+            assert!(
+                (res - expected).abs() < 1e-12,
+                "{degree}, {a}: {res} vs {expected}"
+            );
+        }
+    }
+
+    // affine map of the reference triangle; area is 3, integral of x is 3 * (1 + 3 + 0) / 3
+    let triangle = RegionDescriptor::Simplex(vec![vec![1.0, 1.0], vec![3.0, 1.0], vec![0.0, 4.0]]);
+    let res = compute_multi(|x| x[0], triangle, ComputeMethod::Dunavant { degree: 1 }).unwrap();
+    assert!((res - 4.0).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn grundmann_moller() {
+    let cases: [(usize, Vec<usize>); 5] = [
+        (2, vec![3, 4]),
+        (3, vec![2, 0, 1]),
+        (3, vec![1, 2, 4]),
+        (4, vec![1, 1, 1, 2]),
+        (4, vec![0, 3, 0, 0]),
+    ];
+    for (dim, exponents) in cases {
+        let degree = exponents.iter().sum();
+        let expected = simplex_integral(&exponents);
+        let res = compute_multi(
+            monomial(&exponents),
+            reference(dim),
+            ComputeMethod::GrundmannMoller { degree },
+        )
+        .unwrap();
+        assert!(
+            (res - expected).abs() < 1e-12,
+            "{exponents:?}: {res} vs {expected}"
+        );
+    }
+}
+
+#[test]
+fn polygon() {
+    // L-shape with collinear vertices, clockwise; area is 3
+    let l_shape = vec![
+        [0.0, 0.0],
+        [0.0, 2.0],
+        [1.0, 2.0],
+        [1.0, 1.0],
+        [2.0, 1.0],
+        [2.0, 0.0],
+        [1.0, 0.0],
+    ];
+    let res = compute_multi(
+        |_| 1.0,
+        RegionDescriptor::Polygon(l_shape.clone()),
+        ComputeMethod::Dunavant { degree: 1 },
+    )
+    .unwrap();
+    assert!((res - 3.0).abs() < 1e-12, "{res}");
+
+    // integral of x * y: 1/2 * 2 over [0,1]x[0,2], plus 3/2 * 1/2 over [1,2]x[0,1]
+    let res = compute_multi(
+        |x| x[0] * x[1],
+        RegionDescriptor::Polygon(l_shape),
+        ComputeMethod::GrundmannMoller { degree: 2 },
+    )
+    .unwrap();
+    assert!((res - 1.75).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn simplex_non_finite() {
+    // the single point of the rule is the centroid of the triangle
+    let integral = |policy| {
+        Integraal::default()
+            .method(ComputeMethod::Dunavant { degree: 1 })
+            .function(FunctionDescriptor::MultiClosure(Box::new(|x: &[f64]| {
+                if (x[0] - 1.0 / 3.0).abs() < 1e-12 {
+                    f64::NAN
+                } else {
+                    1.0
+                }
+            })))
+            .region(reference(2))
+            .non_finite_policy(policy)
+            .compute()
+    };
+    match integral(NonFinitePolicy::Error) {
+        Err(IntegraalError::NonFinitePoint { index: 0, point }) => {
+            assert!(
+                point.iter().all(|x| (x - 1.0 / 3.0).abs() < 1e-12),
+                "{point:?}"
+            );
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+    assert_eq!(integral(NonFinitePolicy::ReplaceWithZero), Ok(0.0));
+    for policy in [NonFinitePolicy::Skip, NonFinitePolicy::Interpolate] {
+        assert!(matches!(
+            integral(policy),
+            Err(IntegraalError::InconsistentParameters(_))
+        ));
+    }
+}
+
+#[test]
+fn simplex_incorrect() {
+    let method = ComputeMethod::Dunavant { degree: 2 };
+    assert!(matches!(
+        compute_multi(
+            |_| 1.0,
+            RegionDescriptor::Simplex(vec![vec![0.0, 0.0], vec![1.0, 1.0], vec![2.0, 2.0]]),
+            method
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    // self-intersecting polygon
+    assert!(matches!(
+        compute_multi(
+            |_| 1.0,
+            RegionDescriptor::Polygon(vec![[0.0, 0.0], [1.0, 1.0], [1.0, 0.0], [0.0, 1.0]]),
+            method
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert_eq!(
+        compute_multi(|_| 1.0, reference(3), method),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "simplices that are not triangles",
+        })
+    );
+    assert_eq!(
+        compute_multi(|_| 1.0, reference(2), ComputeMethod::Trapezoid),
+        Err(IntegraalError::Unimplemented {
+            method: ComputeMethod::Trapezoid,
+            context: "regions",
+        })
+    );
+    // regions & domains are exclusive, and require functions of several variables
+    assert!(matches!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::Closure(Box::new(|x: f64| x)))
+            .region(reference(2))
+            .compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    assert!(matches!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::MultiClosure(Box::new(|x: &[f64]| x[0])))
+            .region(reference(2))
+            .domain(DomainDescriptor::linspace(0.0, 1.0, 2))
+            .compute(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    assert!(matches!(
+        Integraal::builder()
+            .region(reference(2))
+            .function(FunctionDescriptor::Values(vec![1.0]))
+            .method(method)
+            .build(),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+}
@@ -0,0 +1,146 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::LogWeight;
+
+// ------ TESTS
+
+fn compute(
+    function: impl Fn(f64) -> f64 + 'static,
+    domain: DomainDescriptor<f64>,
+    method: ComputeMethod,
+) -> Result<f64, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::Closure(Box::new(function)))
+        .domain(domain)
+        .compute()
+}
+
+#[test]
+fn cauchy() {
+    // PV of e^x / x over [-1, 1] is 2 * Shi(1)
+    let res = compute(
+        f64::exp,
+        DomainDescriptor::linspace(-1.0, 1.0, 2),
+        ComputeMethod::CauchyPrincipalValue {
+            pole: 0.0,
+            n_nodes: 10,
+        },
+    )
+    .unwrap();
+    assert!((res - 2.114_501_750_751_457).abs() < 1e-14, "{res}");
+
+    // x^2 / (x - c) = x + c + c^2 / (x - c); the pole may be close to a limit
+    for pole in [0.3_f64, 0.5, 0.9, 1e-6] {
+        let expected = 0.5 + pole + pole * pole * ((1.0 - pole) / pole).ln();
+        let res = compute(
+            |x| x * x,
+            DomainDescriptor::linspace(0.0, 1.0, 2),
+            ComputeMethod::CauchyPrincipalValue { pole, n_nodes: 12 },
+        )
+        .unwrap();
+        assert!(
+            (res - expected).abs() < 1e-12,
+            "{pole}: {res} vs {expected}"
+        );
+    }
+
+    // descending domains flip the sign
+    let res = Integraal::default()
+        .method(ComputeMethod::CauchyPrincipalValue {
+            pole: 0.3,
+            n_nodes: 12,
+        })
+        .function(FunctionDescriptor::Closure(Box::new(|_| 1.0)))
+        .domain(DomainDescriptor::linspace(1.0, 0.0, 2))
+        .allow_descending(true)
+        .compute()
+        .unwrap();
+    assert!((res + (0.7_f64 / 0.3).ln()).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn algebraic_logarithmic() {
+    let method = |alpha, beta, log| ComputeMethod::AlgebraicLogarithmic {
+        alpha,
+        beta,
+        log,
+        n_levels: 5,
+    };
+    let unit = || DomainDescriptor::linspace(0.0, 1.0, 2);
+    let cases = [
+        // integral of x^-0.5
+        (-0.5, 0.0, LogWeight::None, 2.0),
+        // beta function B(0.1, 0.1)
+        (-0.9, -0.9, LogWeight::None, 19.714_639_489_050_164),
+        // integral of log(x)
+        (0.0, 0.0, LogWeight::Start, -1.0),
+        // integral of x^-0.5 * log(x)
+        (-0.5, 0.0, LogWeight::Start, -4.0),
+        // integral of (1 - x)^2 * log(1 - x)
+        (0.0, 2.0, LogWeight::End, -1.0 / 9.0),
+        // integral of log(x) * log(1 - x)
+        (
+            0.0,
+            0.0,
+            LogWeight::Both,
+            2.0 - std::f64::consts::PI.powi(2) / 6.0,
+        ),
+    ];
+    for (alpha, beta, log, expected) in cases {
+        let res = compute(|_| 1.0, unit(), method(alpha, beta, log)).unwrap();
+        assert!(
+            (res - expected).abs() < 1e-12,
+            "{alpha}, {beta}, {log:?}: {res} vs {expected}"
+        );
+    }
+
+    // non-unit domain & non-constant function
+    let res = compute(
+        |x| x,
+        DomainDescriptor::linspace(1.0, 3.0, 2),
+        method(-0.9, -0.5, LogWeight::None),
+    )
+    .unwrap();
+    assert!((res - 11.441_727_008_248_42).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn singular_incorrect() {
+    let method = ComputeMethod::CauchyPrincipalValue {
+        pole: 2.0,
+        n_nodes: 4,
+    };
+    assert_eq!(
+        compute(|x| x, DomainDescriptor::linspace(0.0, 1.0, 2), method),
+        Err(IntegraalError::BadParameters(String::from(
+            "the pole should lie strictly inside the domain (pole: 2.0, limits: 0.0 and 1.0)"
+        )))
+    );
+    let method = ComputeMethod::AlgebraicLogarithmic {
+        alpha: -1.0,
+        beta: 0.0,
+        log: LogWeight::None,
+        n_levels: 5,
+    };
+    assert!(matches!(
+        compute(|x| x, DomainDescriptor::linspace(0.0, 1.0, 2), method),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    let method = ComputeMethod::CauchyPrincipalValue {
+        pole: 0.5,
+        n_nodes: 4,
+    };
+    assert_eq!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::Values(vec![1.0, 2.0, 3.0]))
+            .domain(DomainDescriptor::linspace(0.0, 1.0, 3))
+            .compute(),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "function values",
+        })
+    );
+}
@@ -0,0 +1,185 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{Evaluation, NestedRule, RegionDescriptor};
+use std::{cell::Cell, rc::Rc};
+
+// ------ TESTS
+
+fn evaluate(
+    function: impl Fn(&[f64]) -> f64 + 'static,
+    bounds: Vec<(f64, f64)>,
+    method: ComputeMethod,
+) -> Result<Evaluation<f64>, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(FunctionDescriptor::MultiClosure(Box::new(function)))
+        .region(RegionDescriptor::Hyperrectangle(bounds))
+        .evaluate_detailed()
+}
+
+fn smolyak(rule: NestedRule, level: usize, tolerance: Option<f64>) -> ComputeMethod {
+    ComputeMethod::Smolyak {
+        rule,
+        level,
+        tolerance,
+    }
+}
+
+#[test]
+fn smolyak_exactness() {
+    // the rule of level l contains the 1D rule of level l along each axis
+    for (rule, level, degree) in [
+        (NestedRule::ClenshawCurtis, 3, 9),
+        (NestedRule::GaussPatterson, 3, 23),
+        (NestedRule::GaussPatterson, 5, 95),
+    ] {
+        let res = evaluate(
+            move |x| x[0].powi(degree - 1) + x[0].powi(degree),
+            vec![(-1.0, 1.0)],
+            smolyak(rule, level, None),
+        )
+        .unwrap();
+        let expected = 2.0 / f64::from(degree);
+        assert!(
+            (res.value - expected).abs() < 1e-13,
+            "{rule:?}: {}",
+            res.value
+        );
+    }
+    // sums of functions of a single variable, in 5D
+    let res = evaluate(
+        |x| x.iter().map(|xi| xi * xi).sum(),
+        vec![(0.0, 1.0); 5],
+        smolyak(NestedRule::ClenshawCurtis, 1, None),
+    )
+    .unwrap();
+    assert!((res.value - 5.0 / 3.0).abs() < 1e-13, "{}", res.value);
+}
+
+#[test]
+fn smolyak_smooth() {
+    // exp(x_1 + ... + x_8) over [0, 1]^8
+    let expected = (std::f64::consts::E - 1.0).powi(8);
+    for rule in [NestedRule::ClenshawCurtis, NestedRule::GaussPatterson] {
+        let res = evaluate(
+            |x| x.iter().sum::<f64>().exp(),
+            vec![(0.0, 1.0); 8],
+            smolyak(rule, 4, None),
+        )
+        .unwrap();
+        let (error, indicator) = ((res.value - expected).abs(), res.error.unwrap());
+        assert!(error < 1e-5 * expected, "{rule:?}: {error}");
+        // the indicator overestimates the error
+        assert!(error < indicator, "{rule:?}: {error} vs {indicator}");
+    }
+    // the trapezoid rule converges slowly, but converges
+    let errors: Vec<f64> = [2, 4]
+        .into_iter()
+        .map(|level| {
+            let res = evaluate(
+                |x| (x[0] + x[1]).cos(),
+                vec![(0.0, 1.0), (0.0, 2.0)],
+                smolyak(NestedRule::Trapezoid, level, None),
+            )
+            .unwrap();
+            (res.value - (2.0_f64.cos() + 1.0_f64.cos() - 3.0_f64.cos() - 1.0)).abs()
+        })
+        .collect();
+    assert!(errors[1] < errors[0] / 4.0, "{errors:?}");
+}
+
+#[test]
+fn smolyak_adaptive() {
+    // only the first of 10 variables matters
+    let count = |tolerance| {
+        let evaluations = Rc::new(Cell::new(0));
+        let counter = evaluations.clone();
+        let res = evaluate(
+            move |x| {
+                counter.set(counter.get() + 1);
+                x[0].exp() + 1e-3 * x[1]
+            },
+            vec![(0.0, 1.0); 10],
+            smolyak(NestedRule::ClenshawCurtis, 4, tolerance),
+        )
+        .unwrap();
+        (res, evaluations.get())
+    };
+    let (uniform, n_uniform) = count(None);
+    let (adaptive, n_adaptive) = count(Some(1e-8));
+    let expected = std::f64::consts::E - 1.0 + 5e-4;
+    assert!((uniform.value - expected).abs() < 1e-8, "{}", uniform.value);
+    assert!(
+        (adaptive.value - expected).abs() < 1e-8,
+        "{}",
+        adaptive.value
+    );
+    assert!(adaptive.error.unwrap() <= 1e-8);
+    assert!(10 * n_adaptive < n_uniform, "{n_adaptive} vs {n_uniform}");
+
+    // the tolerance cannot be reached using the points of the rule of level 1
+    assert!(matches!(
+        evaluate(
+            |x| x[0].exp(),
+            vec![(0.0, 1.0); 2],
+            smolyak(NestedRule::ClenshawCurtis, 1, Some(1e-12))
+        ),
+        Err(IntegraalError::NotConverged { .. })
+    ));
+}
+
+#[test]
+fn smolyak_incorrect() {
+    let f = |x: &[f64]| x[0];
+    let method = smolyak(NestedRule::ClenshawCurtis, 2, None);
+    assert!(matches!(
+        evaluate(f, vec![(0.0, 1.0)], smolyak(NestedRule::Trapezoid, 0, None)),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        evaluate(
+            f,
+            vec![(0.0, 1.0)],
+            smolyak(NestedRule::GaussPatterson, 6, None)
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        evaluate(f, vec![(1.0, 0.0)], method),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        evaluate(f, Vec::new(), method),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert_eq!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::MultiClosure(Box::new(f)))
+            .region(RegionDescriptor::Disc {
+                center: [0.0; 2],
+                radius: 1.0,
+            })
+            .compute(),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "discs",
+        })
+    );
+    assert_eq!(
+        Integraal::default()
+            .method(method)
+            .function(FunctionDescriptor::Closure(Box::new(|x| x)))
+            .domain(DomainDescriptor::Uniform {
+                start: 0.0,
+                step: 0.1,
+                n_step: 11,
+            })
+            .compute(),
+        Err(IntegraalError::Unimplemented {
+            method,
+            context: "one-dimensional domains",
+        })
+    );
+}
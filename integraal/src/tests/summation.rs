@@ -0,0 +1,104 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::Summation;
+
+// ------ TESTS
+
+// long single-precision integrations, where round-off dominates the discretization error
+
+const N_STEP: usize = 1_000_001;
+const STEP: f32 = 1e-6;
+
+const STRATEGIES: [Summation; 4] = [
+    Summation::Naive,
+    Summation::Pairwise,
+    Summation::KahanBabuskaNeumaier,
+    Summation::Exact,
+];
+
+fn compute(function: FunctionDescriptor<f32>, method: ComputeMethod, summation: Summation) -> f32 {
+    Integraal::default()
+        .domain(DomainDescriptor::Uniform {
+            start: 0.0,
+            step: STEP,
+            n_step: N_STEP,
+        })
+        .function(function)
+        .method(method)
+        .summation(summation)
+        .compute()
+        .unwrap()
+}
+
+#[test]
+fn constant_f32() {
+    // y = f(x) = 1 from 0 to 1; the exact result is (N_STEP - 1) * STEP
+    let expected = (N_STEP - 1) as f64 * f64::from(STEP);
+    let errors: Vec<f64> = STRATEGIES
+        .iter()
+        .map(|summation| {
+            let res = compute(
+                FunctionDescriptor::Values(vec![1.0; N_STEP]),
+                ComputeMethod::Trapezoid,
+                *summation,
+            );
+            (f64::from(res) - expected).abs()
+        })
+        .collect();
+
+    // naive summation is off by about a percent; rounding errors of this sum are systematic,
+    // which also affects the compensation term of the Kahan-Babuška-Neumaier summation
+    assert!(errors[0] > 1e-3);
+    assert!(errors[1] < 1e-7);
+    assert!(errors[2] < errors[0] / 100.0);
+    assert!(errors[3] < 1e-7);
+}
+
+#[test]
+fn sine_f32() {
+    // y = f(x) = sin(x) from 0 to 1
+    let expected = 1.0 - 1.0_f64.cos();
+    let errors: Vec<f64> = STRATEGIES
+        .iter()
+        .map(|summation| {
+            let res = compute(
+                FunctionDescriptor::Closure(Box::new(f32::sin)),
+                ComputeMethod::Simpson,
+                *summation,
+            );
+            (f64::from(res) - expected).abs()
+        })
+        .collect();
+
+    for err in &errors[1..] {
+        assert!(*err < errors[0] / 10.0);
+    }
+}
+
+#[test]
+fn exact_cancellation() {
+    // large terms cancel out; only the exact summation recovers the small ones
+    let vals = vec![1.0, 1e100, 1.0, -1e100, 1.0];
+    let args = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    for (summation, expected) in [
+        (Summation::Naive, 1.0),
+        (Summation::Pairwise, 1.0),
+        (Summation::KahanBabuskaNeumaier, 3.0),
+        (Summation::Exact, 3.0),
+    ] {
+        let mut vals = vals.clone();
+        vals.push(0.0);
+        let res: f64 = Integraal::default()
+            .domain(DomainDescriptor::Explicit(args.clone()))
+            .function(FunctionDescriptor::Values(vals))
+            .method(ComputeMethod::RectangleLeft)
+            .summation(summation)
+            .compute()
+            .unwrap();
+        assert!(
+            (res - expected).abs() < f64::EPSILON,
+            "{summation:?}: {res}"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+// ------ IMPORTS
+
+use super::*;
+use crate::{RegionDescriptor, SurfaceDescriptor};
+use std::{
+    f64::consts::{PI, TAU},
+    sync::Arc,
+};
+
+// ------ TESTS
+
+fn unit_sphere(n: usize) -> SurfaceDescriptor<f64> {
+    SurfaceDescriptor::new(
+        Arc::new(|theta: f64, phi: f64| {
+            vec![
+                theta.sin() * phi.cos(),
+                theta.sin() * phi.sin(),
+                theta.cos(),
+            ]
+        }),
+        DomainDescriptor::linspace(0.0, PI, n),
+        DomainDescriptor::linspace(0.0, TAU, n),
+    )
+}
+
+fn compute(
+    function: FunctionDescriptor<f64>,
+    surface: SurfaceDescriptor<f64>,
+    method: ComputeMethod,
+) -> Result<f64, IntegraalError> {
+    Integraal::default()
+        .method(method)
+        .function(function)
+        .region(RegionDescriptor::Surface(surface))
+        .compute()
+}
+
+#[test]
+fn scalar() {
+    // area of the unit sphere, using finite differences
+    let res = compute(
+        FunctionDescriptor::MultiClosure(Box::new(|_| 1.0)),
+        unit_sphere(101),
+        ComputeMethod::Simpson,
+    )
+    .unwrap();
+    assert!((res - 4.0 * PI).abs() < 1e-6, "{res}");
+
+    // lateral area of a cylinder of height 2, using exact partial derivatives
+    let cylinder = SurfaceDescriptor::new(
+        Arc::new(|theta: f64, z: f64| vec![theta.cos(), theta.sin(), z]),
+        DomainDescriptor::linspace(0.0, TAU, 9),
+        DomainDescriptor::linspace(0.0, 2.0, 3),
+    )
+    .partials(Arc::new(|theta: f64, _| {
+        (vec![-theta.sin(), theta.cos(), 0.0], vec![0.0, 0.0, 1.0])
+    }));
+    let res = compute(
+        FunctionDescriptor::MultiClosure(Box::new(|_| 1.0)),
+        cylinder,
+        ComputeMethod::Trapezoid,
+    )
+    .unwrap();
+    assert!((res - 2.0 * TAU).abs() < 1e-12, "{res}");
+}
+
+#[test]
+fn values() {
+    // z = u + v over the unit square, whose area element is sqrt(3); integral of u * v
+    let plane = SurfaceDescriptor::new(
+        Arc::new(|u: f64, v: f64| vec![u, v, u + v]),
+        DomainDescriptor::linspace(0.0, 1.0, 3),
+        DomainDescriptor::linspace(0.0, 1.0, 5),
+    );
+    let vals = (0..5)
+        .flat_map(|j| (0..3).map(move |i| f64::from(i) * 0.5 * f64::from(j) * 0.25))
+        .collect();
+    let res = compute(
+        FunctionDescriptor::Values(vals),
+        plane,
+        ComputeMethod::Simpson,
+    )
+    .unwrap();
+    assert!((res - 3.0_f64.sqrt() / 4.0).abs() < 1e-9, "{res}");
+}
+
+#[test]
+fn flux() {
+    // flux of (x, y, z) through the unit sphere is three times its volume
+    let res = compute(
+        FunctionDescriptor::VectorField(Box::new(|p: &[f64]| p.to_vec())),
+        unit_sphere(101),
+        ComputeMethod::Simpson,
+    )
+    .unwrap();
+    assert!((res - 4.0 * PI).abs() < 1e-6, "{res}");
+}
+
+#[test]
+fn surface_incorrect() {
+    let plane = || {
+        SurfaceDescriptor::new(
+            Arc::new(|u: f64, v: f64| vec![u, v]),
+            DomainDescriptor::linspace(0.0, 1.0, 3),
+            DomainDescriptor::linspace(0.0, 1.0, 3),
+        )
+    };
+    assert_eq!(
+        compute(
+            FunctionDescriptor::Values(vec![1.0; 8]),
+            plane(),
+            ComputeMethod::Trapezoid
+        ),
+        Err(IntegraalError::LengthMismatch {
+            domain: 9,
+            function: 8,
+        })
+    );
+    assert!(matches!(
+        compute(
+            FunctionDescriptor::VectorField(Box::new(|p: &[f64]| p.to_vec())),
+            plane(),
+            ComputeMethod::Trapezoid
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+    assert!(matches!(
+        compute(
+            FunctionDescriptor::Closure(Box::new(|x| x)),
+            plane(),
+            ComputeMethod::Trapezoid
+        ),
+        Err(IntegraalError::InconsistentParameters(_))
+    ));
+    let mut surface = plane();
+    surface.u = DomainDescriptor::Piecewise(vec![]);
+    assert!(matches!(
+        compute(
+            FunctionDescriptor::MultiClosure(Box::new(|_| 1.0)),
+            surface,
+            ComputeMethod::Trapezoid
+        ),
+        Err(IntegraalError::BadParameters(_))
+    ));
+}